@@ -0,0 +1,102 @@
+//! The `benchmark-all` subcommand.
+
+use sc_cli::Error;
+use std::{path::PathBuf, process::Command};
+use structopt::StructOpt;
+
+/// The pallets of this repository, with the directory holding their sources.
+const LOCAL_PALLETS: &[(&str, &str)] = &[
+	("pallet_template", "template"),
+	("pallet_block_metrics", "block-metrics"),
+	("pallet_block_times", "block-times"),
+	("pallet_claims", "claims"),
+	("pallet_credentials", "credentials"),
+	("pallet_event_index", "event-index"),
+	("pallet_idempotency", "idempotency"),
+	("pallet_notaries", "notaries"),
+	("pallet_organizations", "organizations"),
+	("pallet_parameters", "parameters"),
+	("pallet_rate_limit", "rate-limit"),
+	("pallet_schedules", "schedules"),
+	("pallet_sponsorship", "sponsorship"),
+	("pallet_whitelist", "whitelist"),
+];
+
+/// The upstream pallets, whose weights are only written to `output` for comparison.
+///
+/// `pallet_recovery` is missing because it has no benchmarks at the Substrate version the
+/// runtime uses; its calls are weighed with the constant weights of its `#[pallet::weight]`
+/// annotations.
+const UPSTREAM_PALLETS: &[&str] =
+	&["frame_system", "pallet_balances", "pallet_timestamp", "pallet_treasury", "pallet_utility"];
+
+/// The `benchmark-all` command.
+///
+/// Runs `benchmark` for every pallet of the runtime, one child process per pallet, on the dev
+/// chain with the compiled Wasm runtime. The weights of the pallets of this repository
+/// replace their `weights.rs`, which the runtime uses, so run it from the root of the
+/// repository. The weights of upstream pallets go to `output`.
+#[derive(Debug, StructOpt)]
+pub struct BenchmarkAllCmd {
+	/// Number of samples of every component.
+	#[structopt(long, default_value = "50")]
+	pub steps: u32,
+
+	/// Number of times every sample is repeated.
+	#[structopt(long, default_value = "20")]
+	pub repeat: u32,
+
+	/// The directory the weights of upstream pallets are written to.
+	#[structopt(long, parse(from_os_str), default_value = "weights")]
+	pub output: PathBuf,
+
+	/// The template the weights of the pallets of this repository are rendered with.
+	#[structopt(long, parse(from_os_str), default_value = ".maintain/frame-weight-template.hbs")]
+	pub template: PathBuf,
+}
+
+impl BenchmarkAllCmd {
+	/// Run the command.
+	pub fn run(&self) -> Result<(), Error> {
+		std::fs::create_dir_all(&self.output)?;
+
+		for (pallet, dir) in LOCAL_PALLETS {
+			let output = PathBuf::from("pallets").join(dir).join("src").join("weights.rs");
+			let mut cmd = self.benchmark(pallet)?;
+			cmd.arg("--template").arg(&self.template).arg("--output").arg(output);
+			Self::spawn(pallet, cmd)?;
+		}
+		for pallet in UPSTREAM_PALLETS {
+			let mut cmd = self.benchmark(pallet)?;
+			cmd.arg("--output").arg(self.output.join(format!("{}.rs", pallet)));
+			Self::spawn(pallet, cmd)?;
+		}
+		Ok(())
+	}
+
+	/// The `benchmark` invocation measuring every extrinsic of `pallet`.
+	fn benchmark(&self, pallet: &str) -> Result<Command, Error> {
+		let mut cmd = Command::new(std::env::current_exe()?);
+		cmd.arg("benchmark")
+			.args(&["--chain", "dev"])
+			.args(&["--execution", "wasm"])
+			.args(&["--wasm-execution", "compiled"])
+			.args(&["--pallet", pallet])
+			.args(&["--extrinsic", "*"])
+			.arg("--steps")
+			.arg(self.steps.to_string())
+			.arg("--repeat")
+			.arg(self.repeat.to_string());
+		Ok(cmd)
+	}
+
+	/// Run `cmd`, failing if it does not succeed.
+	fn spawn(pallet: &str, mut cmd: Command) -> Result<(), Error> {
+		println!("*** Benchmarking {}", pallet);
+		let status = cmd.status()?;
+		if !status.success() {
+			return Err(format!("Benchmarking {} failed with {}", pallet, status).into())
+		}
+		Ok(())
+	}
+}
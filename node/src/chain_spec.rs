@@ -1,12 +1,15 @@
 use node_template_runtime::{
-	AccountId, AuraConfig, BalancesConfig, GenesisConfig, GrandpaConfig, Signature, SudoConfig,
-	SystemConfig, WASM_BINARY,
+	AccountId, AuraConfig, Balance, BalancesConfig, ClaimsConfig, ExistentialDeposit,
+	GenesisConfig, GrandpaConfig, Signature, SudoConfig, SystemConfig, WASM_BINARY,
 };
-use sc_service::ChainType;
+use pallet_claims::EthereumAddress;
+use sc_service::{config::MultiaddrWithPeerId, ChainType, Properties};
+use serde::Deserialize;
 use sp_consensus_aura::sr25519::AuthorityId as AuraId;
 use sp_core::{sr25519, Pair, Public};
 use sp_finality_grandpa::AuthorityId as GrandpaId;
 use sp_runtime::traits::{IdentifyAccount, Verify};
+use std::path::Path;
 
 // The URL for the telemetry server.
 // const STAGING_TELEMETRY_URL: &str = "wss://telemetry.polkadot.io/submit/";
@@ -122,6 +125,50 @@ pub fn local_testnet_config() -> Result<ChainSpec, String> {
 	))
 }
 
+/// A local testnet whose authorities are derived from `seeds`, as launched by the
+/// `local-testnet` subcommand.
+pub fn local_validators_config(seeds: Vec<String>) -> Result<ChainSpec, String> {
+	let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+
+	Ok(ChainSpec::from_genesis(
+		// Name
+		"Local Testnet",
+		// ID
+		"local_testnet",
+		ChainType::Local,
+		move || {
+			let mut endowed_accounts: Vec<AccountId> =
+				["Alice", "Bob", "Charlie", "Dave", "Eve", "Ferdie"]
+					.iter()
+					.map(|seed| get_account_id_from_seed::<sr25519::Public>(seed))
+					.collect();
+			endowed_accounts
+				.extend(seeds.iter().map(|seed| get_account_id_from_seed::<sr25519::Public>(seed)));
+
+			testnet_genesis(
+				wasm_binary,
+				// Initial PoA authorities
+				seeds.iter().map(|seed| authority_keys_from_seed(seed)).collect(),
+				// Sudo account
+				get_account_id_from_seed::<sr25519::Public>("Alice"),
+				// Pre-funded accounts
+				endowed_accounts,
+				true,
+			)
+		},
+		// Bootnodes
+		vec![],
+		// Telemetry
+		None,
+		// Protocol ID
+		None,
+		// Properties
+		None,
+		// Extensions
+		None,
+	))
+}
+
 /// Configure initial storage state for FRAME modules.
 fn testnet_genesis(
 	wasm_binary: &[u8],
@@ -150,5 +197,162 @@ fn testnet_genesis(
 			key: root_key,
 		},
 		transaction_payment: Default::default(),
+		claims: Default::default(),
+		treasury: Default::default(),
+	}
+}
+
+/// A network description read by the `build-spec-ext` subcommand.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct NetworkConfig {
+	/// The human readable name of the chain.
+	pub name: String,
+	/// The identifier of the chain, also used as the name of its database directory.
+	pub id: String,
+	#[serde(default)]
+	pub chain_type: ChainType,
+	#[serde(default)]
+	pub boot_nodes: Vec<String>,
+	pub protocol_id: Option<String>,
+	pub properties: Option<Properties>,
+	/// The initial PoA authorities.
+	pub authorities: Vec<AuthorityKeys>,
+	/// The sudo account.
+	pub sudo: AccountId,
+	/// The pre-funded accounts and their balances.
+	#[serde(default)]
+	pub endowed_accounts: Vec<(AccountId, Balance)>,
+	/// The claims of Ethereum addresses on genesis tokens.
+	#[serde(default)]
+	pub claims: Vec<EthereumClaim>,
+	/// A JSON object merged into the generated runtime genesis, for settings that have no
+	/// dedicated field.
+	pub patch: Option<serde_json::Value>,
+}
+
+/// The session keys of an authority, given as SS58 addresses.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AuthorityKeys {
+	pub aura: AuraId,
+	pub grandpa: GrandpaId,
+}
+
+/// A genesis claim of an Ethereum address.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase", deny_unknown_fields)]
+pub struct EthereumClaim {
+	/// The address as `0x` prefixed hex.
+	pub address: String,
+	pub amount: Balance,
+	#[serde(default)]
+	pub statement_required: bool,
+}
+
+/// Build a chain specification from the network description at `path`.
+pub fn from_network_config(path: &Path) -> Result<ChainSpec, String> {
+	let wasm_binary = WASM_BINARY.ok_or_else(|| "Development wasm not available".to_string())?;
+
+	let file = std::fs::File::open(path)
+		.map_err(|e| format!("Error opening network config `{}`: {}", path.display(), e))?;
+	let config: NetworkConfig = serde_json::from_reader(file)
+		.map_err(|e| format!("Error parsing network config `{}`: {}", path.display(), e))?;
+
+	let boot_nodes = config
+		.boot_nodes
+		.iter()
+		.map(|addr| addr.parse::<MultiaddrWithPeerId>())
+		.collect::<Result<Vec<_>, _>>()
+		.map_err(|e| format!("Invalid boot node: {}", e))?;
+	let claims = config
+		.claims
+		.iter()
+		.map(|claim| {
+			let address = parse_ethereum_address(&claim.address)?;
+			if claim.amount < ExistentialDeposit::get() {
+				return Err(format!(
+					"Claim of `{}` is below the existential deposit of {}",
+					claim.address,
+					ExistentialDeposit::get()
+				))
+			}
+			Ok((address, claim.amount, claim.statement_required))
+		})
+		.collect::<Result<Vec<_>, String>>()?;
+
+	let genesis_config = config.clone();
+	let spec = ChainSpec::from_genesis(
+		&config.name,
+		&config.id,
+		config.chain_type.clone(),
+		move || {
+			let mut genesis = testnet_genesis(
+				wasm_binary,
+				genesis_config
+					.authorities
+					.iter()
+					.map(|k| (k.aura.clone(), k.grandpa.clone()))
+					.collect(),
+				genesis_config.sudo.clone(),
+				vec![],
+				false,
+			);
+			genesis.balances.balances = genesis_config.endowed_accounts.clone();
+			genesis.claims = ClaimsConfig { claims: claims.clone() };
+			genesis
+		},
+		boot_nodes,
+		None,
+		config.protocol_id.as_deref(),
+		config.properties.clone(),
+		None,
+	);
+
+	match config.patch {
+		Some(patch) => apply_genesis_patch(spec, patch),
+		None => Ok(spec),
+	}
+}
+
+/// Merge `patch` into the runtime genesis of `spec`.
+fn apply_genesis_patch(spec: ChainSpec, patch: serde_json::Value) -> Result<ChainSpec, String> {
+	let json = sc_service::chain_ops::build_spec(&spec, false)?;
+	let mut value: serde_json::Value =
+		serde_json::from_str(&json).map_err(|e| format!("Error reading chain spec: {}", e))?;
+	let runtime = value
+		.pointer_mut("/genesis/runtime")
+		.ok_or_else(|| "Chain spec has no runtime genesis".to_string())?;
+	merge_json(runtime, patch);
+
+	let bytes =
+		serde_json::to_vec(&value).map_err(|e| format!("Error writing chain spec: {}", e))?;
+	ChainSpec::from_json_bytes(bytes)
+}
+
+/// Recursively merge `patch` into `base`: objects are merged key by key, any other value
+/// replaces the one in `base`, and `null` removes the key.
+fn merge_json(base: &mut serde_json::Value, patch: serde_json::Value) {
+	match (base, patch) {
+		(serde_json::Value::Object(base), serde_json::Value::Object(patch)) =>
+			for (key, value) in patch {
+				if value.is_null() {
+					base.remove(&key);
+				} else {
+					merge_json(base.entry(key).or_insert(serde_json::Value::Null), value);
+				}
+			},
+		(base, patch) => *base = patch,
+	}
+}
+
+fn parse_ethereum_address(s: &str) -> Result<EthereumAddress, String> {
+	let bytes = sp_core::bytes::from_hex(s)
+		.map_err(|e| format!("Invalid Ethereum address `{}`: {}", s, e))?;
+	let mut address = EthereumAddress::default();
+	if bytes.len() != address.0.len() {
+		return Err(format!("Invalid Ethereum address `{}`: expected 20 bytes", s))
 	}
+	address.0.copy_from_slice(&bytes);
+	Ok(address)
 }
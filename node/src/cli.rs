@@ -1,4 +1,5 @@
 use sc_cli::RunCmd;
+use std::path::PathBuf;
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt)]
@@ -8,15 +9,60 @@ pub struct Cli {
 
 	#[structopt(flatten)]
 	pub run: RunCmd,
+
+	/// Author a block as soon as a transaction arrives, or when requested through the
+	/// `engine_createBlock` RPC, instead of running Aura and GRANDPA. Meant for local
+	/// development together with `--dev`.
+	#[structopt(long = "dev-instant-seal")]
+	pub instant_seal: bool,
+
+	#[structopt(flatten)]
+	pub future_pool: FuturePoolParams,
+}
+
+/// Transaction pool settings complementing `--pool-limit` and `--pool-kbytes`, which only size
+/// the queue of transactions ready for inclusion.
+///
+/// The defaults leave room for businesses submitting large batches of transactions with
+/// consecutive nonces, which wait in the future queue until the earlier nonces are included.
+#[derive(Debug, StructOpt)]
+pub struct FuturePoolParams {
+	/// Maximum number of transactions waiting for an earlier nonce.
+	#[structopt(long = "pool-future-limit", value_name = "COUNT", default_value = "4096")]
+	pub pool_future_limit: usize,
+
+	/// Maximum number of kilobytes of all transactions waiting for an earlier nonce.
+	#[structopt(long = "pool-future-kbytes", value_name = "COUNT", default_value = "10240")]
+	pub pool_future_kbytes: usize,
+
+	/// Number of seconds an included or invalid transaction is refused by the pool.
+	#[structopt(long = "pool-ban-seconds", value_name = "SECONDS", default_value = "300")]
+	pub pool_ban_seconds: u64,
+}
+
+impl FuturePoolParams {
+	/// Apply the settings to the transaction pool options of a node.
+	pub fn apply(&self, options: &mut sc_transaction_pool::Options) {
+		options.future.count = self.pool_future_limit;
+		options.future.total_bytes = self.pool_future_kbytes * 1024;
+		options.ban_time = std::time::Duration::from_secs(self.pool_ban_seconds);
+	}
 }
 
 #[derive(Debug, StructOpt)]
 pub enum Subcommand {
 	/// Key management cli utilities
 	Key(sc_cli::KeySubcommand),
+
+	/// Generate session keys into the keystore and print them for registration on chain.
+	GenerateSessionKeys(crate::session_keys::GenerateSessionKeysCmd),
+
 	/// Build a chain specification.
 	BuildSpec(sc_cli::BuildSpecCmd),
 
+	/// Build a chain specification from a network description file.
+	BuildSpecExt(BuildSpecExtCmd),
+
 	/// Validate blocks.
 	CheckBlock(sc_cli::CheckBlockCmd),
 
@@ -24,8 +70,15 @@ pub enum Subcommand {
 	ExportBlocks(sc_cli::ExportBlocksCmd),
 
 	/// Export the state of a given block into a chain spec.
+	#[structopt(visible_alias = "snapshot-state")]
 	ExportState(sc_cli::ExportStateCmd),
 
+	/// Create a node database from a snapshot.
+	RestoreState(crate::snapshot::RestoreStateCmd),
+
+	/// Replay recorded blocks on a snapshot with the runtime of this node.
+	ReplayBlocks(crate::replay::ReplayBlocksCmd),
+
 	/// Import blocks.
 	ImportBlocks(sc_cli::ImportBlocksCmd),
 
@@ -35,7 +88,40 @@ pub enum Subcommand {
 	/// Revert the chain to a previous state.
 	Revert(sc_cli::RevertCmd),
 
+	/// Run a local network of validator nodes with generated keys.
+	LocalTestnet(crate::local_testnet::LocalTestnetCmd),
+
+	/// Check a runtime upgrade and print the call installing it.
+	UpgradeRuntime(crate::upgrade::UpgradeRuntimeCmd),
+
 	/// The custom benchmark subcommand benchmarking runtime pallets.
 	#[structopt(name = "benchmark", about = "Benchmark runtime pallets.")]
 	Benchmark(frame_benchmarking_cli::BenchmarkCmd),
+
+	/// Benchmark every runtime pallet and regenerate the weights of the local ones.
+	BenchmarkAll(crate::benchmark_all::BenchmarkAllCmd),
+
+	/// Try some command against runtime state.
+	#[cfg(feature = "try-runtime")]
+	TryRuntime(try_runtime_cli::TryRuntimeCmd),
+
+	/// Try some command against runtime state. Note: `try-runtime` feature must be enabled.
+	#[cfg(not(feature = "try-runtime"))]
+	TryRuntime,
+}
+
+/// The `build-spec-ext` command.
+///
+/// Unlike `build-spec`, which exports one of the chain specifications hardcoded in
+/// `chain_spec.rs`, this builds the specification from a JSON file describing the network, so
+/// the same file always produces the same chain specification.
+#[derive(Debug, StructOpt)]
+pub struct BuildSpecExtCmd {
+	/// The JSON file describing the network.
+	#[structopt(parse(from_os_str))]
+	pub config: PathBuf,
+
+	/// Force raw genesis storage output.
+	#[structopt(long = "raw")]
+	pub raw: bool,
 }
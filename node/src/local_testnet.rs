@@ -0,0 +1,101 @@
+//! The `local-testnet` subcommand.
+
+use crate::chain_spec;
+use sc_cli::Error;
+use sc_keystore::LocalKeystore;
+use sc_service::{BasePath, ChainSpec};
+use sp_core::crypto::key_types;
+use sp_keystore::SyncCryptoStore;
+use std::{path::PathBuf, process::Command};
+use structopt::StructOpt;
+
+/// The `local-testnet` command.
+///
+/// Writes a chain specification with `validators` authorities, puts the keys of validator `i`,
+/// derived from `//Validator<i>`, into the keystore of node `i` and runs every node as a child
+/// process. The nodes find each other through mDNS. Stop the network with Ctrl-C; the chain
+/// state is kept in `base-path` for the next run.
+#[derive(Debug, StructOpt)]
+pub struct LocalTestnetCmd {
+	/// The number of validator nodes.
+	#[structopt(long, default_value = "3")]
+	pub validators: u16,
+
+	/// The directory holding the chain specification and the data of every node.
+	#[structopt(long, parse(from_os_str), default_value = "local-testnet")]
+	pub base_path: PathBuf,
+
+	/// The p2p port of the first node. Node `i` listens on this port plus `i`.
+	#[structopt(long, default_value = "30333")]
+	pub port: u16,
+
+	/// The WebSockets RPC port of the first node. Node `i` listens on this port plus `i`.
+	#[structopt(long, default_value = "9944")]
+	pub ws_port: u16,
+
+	/// The HTTP RPC port of the first node. Node `i` listens on this port plus `i`.
+	#[structopt(long, default_value = "9933")]
+	pub rpc_port: u16,
+}
+
+impl LocalTestnetCmd {
+	/// Run the command.
+	pub fn run(&self) -> Result<(), Error> {
+		if self.validators == 0 {
+			return Err("At least one validator is required".into())
+		}
+		// Fail before starting any node if the ports of the last one are out of range.
+		self.ports(self.validators - 1)?;
+
+		let seeds: Vec<String> = (0..self.validators).map(|i| format!("Validator{}", i)).collect();
+		let spec = chain_spec::local_validators_config(seeds.clone())?;
+		std::fs::create_dir_all(&self.base_path)?;
+		let spec_path = self.base_path.join("chain-spec.json");
+		std::fs::write(&spec_path, spec.as_json(true)?)?;
+
+		let mut nodes = Vec::new();
+		for (i, seed) in seeds.iter().enumerate() {
+			let node_path = self.base_path.join(format!("node{}", i));
+			let keystore_path = BasePath::new(&node_path).config_dir(spec.id()).join("keystore");
+			let keystore = LocalKeystore::open(keystore_path, None)?;
+			let suri = format!("//{}", seed);
+			SyncCryptoStore::sr25519_generate_new(&keystore, key_types::AURA, Some(&suri))
+				.map_err(|_| Error::KeyStoreOperation)?;
+			SyncCryptoStore::ed25519_generate_new(&keystore, key_types::GRANDPA, Some(&suri))
+				.map_err(|_| Error::KeyStoreOperation)?;
+
+			let (port, ws_port, rpc_port) = self.ports(i as u16)?;
+			let node = Command::new(std::env::current_exe()?)
+				.arg("--chain")
+				.arg(&spec_path)
+				.arg("--base-path")
+				.arg(&node_path)
+				.arg("--validator")
+				.args(&["--name", seed.as_str()])
+				.arg("--port")
+				.arg(port.to_string())
+				.arg("--ws-port")
+				.arg(ws_port.to_string())
+				.arg("--rpc-port")
+				.arg(rpc_port.to_string())
+				.spawn()?;
+			println!("{}: ws://127.0.0.1:{} (pid {})", seed, ws_port, node.id());
+			nodes.push(node);
+		}
+
+		for mut node in nodes {
+			node.wait()?;
+		}
+		Ok(())
+	}
+
+	/// The p2p, WebSockets RPC and HTTP RPC ports of node `offset`.
+	fn ports(&self, offset: u16) -> Result<(u16, u16, u16), Error> {
+		let port = |base: u16, name: &str| {
+			base.checked_add(offset).ok_or_else(|| {
+				Error::Input(format!("The {} port of node {} exceeds {}", name, offset, u16::MAX))
+			})
+		};
+		Ok((port(self.port, "p2p")?, port(self.ws_port, "ws")?, port(self.rpc_port, "rpc")?))
+	}
+}
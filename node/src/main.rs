@@ -4,9 +4,16 @@
 mod chain_spec;
 #[macro_use]
 mod service;
+mod benchmark_all;
 mod cli;
 mod command;
+mod local_testnet;
+mod metrics;
+mod replay;
 mod rpc;
+mod session_keys;
+mod snapshot;
+mod upgrade;
 
 fn main() -> sc_cli::Result<()> {
 	command::run()
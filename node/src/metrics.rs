@@ -0,0 +1,50 @@
+//! Prometheus metrics read from block headers.
+
+use futures::StreamExt;
+use pallet_block_metrics::WeightUsage;
+use sc_client_api::BlockchainEvents;
+use sp_runtime::{
+	generic::DigestItem,
+	traits::{Block as BlockT, Header as HeaderT},
+};
+use std::sync::Arc;
+use substrate_prometheus_endpoint::{register, GaugeVec, Opts, PrometheusError, Registry, U64};
+
+/// The weight consumed by the best block, as reported in its digest by `pallet-block-metrics`.
+pub struct BlockWeightMetrics {
+	weight: GaugeVec<U64>,
+}
+
+impl BlockWeightMetrics {
+	/// Register the metrics with `registry`.
+	pub fn register(registry: &Registry) -> Result<Self, PrometheusError> {
+		Ok(Self {
+			weight: register(
+				GaugeVec::new(
+					Opts::new("block_weight_used", "Weight consumed by the best block per class"),
+					&["class"],
+				)?,
+				registry,
+			)?,
+		})
+	}
+
+	/// Update the metrics for every new best block imported by `client`.
+	pub async fn run<B: BlockT, C: BlockchainEvents<B>>(self, client: Arc<C>) {
+		let mut imports = client.import_notification_stream();
+		while let Some(notification) = imports.next().await {
+			if !notification.is_new_best {
+				continue
+			}
+			let usage = notification.header.digest().logs().iter().find_map(|item| match item {
+				DigestItem::Other(data) => WeightUsage::from_digest_data(data),
+				_ => None,
+			});
+			if let Some(usage) = usage {
+				self.weight.with_label_values(&["normal"]).set(usage.normal);
+				self.weight.with_label_values(&["operational"]).set(usage.operational);
+				self.weight.with_label_values(&["mandatory"]).set(usage.mandatory);
+			}
+		}
+	}
+}
@@ -0,0 +1,103 @@
+//! The `replay-blocks` subcommand.
+
+use crate::chain_spec;
+use node_template_runtime::{Block, Executive, Hash, Header, Runtime, UncheckedExtrinsic};
+use sc_cli::Error;
+use sp_runtime::{
+	codec::Decode,
+	generic::SignedBlock,
+	traits::{Block as _, Header as _},
+	BuildStorage,
+};
+use std::{
+	panic::AssertUnwindSafe,
+	path::{Path, PathBuf},
+};
+use structopt::StructOpt;
+
+/// The `replay-blocks` command.
+///
+/// Loads the state of a block from a snapshot written by `snapshot-state`, then executes the
+/// blocks recorded after it by `export-blocks --binary` with the runtime the node was built
+/// with, the candidate runtime. If its `spec_version` is higher than the one in the snapshot,
+/// its migrations run in the first block, as they would after an upgrade. The state root after
+/// every block is compared with the one in the recorded header, and the command fails at the
+/// first block where they diverge.
+#[derive(Debug, StructOpt)]
+pub struct ReplayBlocksCmd {
+	/// The snapshot written by `snapshot-state`.
+	#[structopt(long, parse(from_os_str))]
+	pub snapshot: PathBuf,
+
+	/// The blocks following the snapshot, written by `export-blocks --binary`.
+	#[structopt(long, parse(from_os_str))]
+	pub blocks: PathBuf,
+}
+
+impl ReplayBlocksCmd {
+	/// Run the command.
+	pub fn run(&self) -> Result<(), Error> {
+		let spec = chain_spec::ChainSpec::from_json_file(self.snapshot.clone())?;
+		let mut ext = sp_io::TestExternalities::new(spec.build_storage()?);
+		let mut last = ext.execute_with(frame_system::Pallet::<Runtime>::block_number);
+
+		let blocks = read_blocks(&self.blocks)?;
+		for block in &blocks {
+			let (header, extrinsics) = block.block.clone().deconstruct();
+			let number = *header.number();
+			if number != last + 1 {
+				return Err(format!("Block {} does not follow block {}", number, last).into())
+			}
+
+			let replayed = std::panic::catch_unwind(AssertUnwindSafe(|| {
+				ext.execute_with(|| execute(header.clone(), extrinsics))
+			}));
+			let root = match replayed {
+				Ok(Ok(root)) => root,
+				Ok(Err(e)) => return Err(format!("Block {}: {}", number, e).into()),
+				Err(_) => return Err(format!("Block {} panicked", number).into()),
+			};
+			if root != *header.state_root() {
+				return Err(format!(
+					"Block {} ({}) diverges: state root {} instead of {}",
+					number,
+					header.hash(),
+					root,
+					header.state_root()
+				)
+				.into())
+			}
+			println!("Block {} ({}) matches", number, header.hash());
+			last = number;
+		}
+
+		println!("Replayed {} blocks without divergence", blocks.len());
+		Ok(())
+	}
+}
+
+/// Read the blocks of a file written by `export-blocks --binary`.
+fn read_blocks(path: &Path) -> Result<Vec<SignedBlock<Block>>, Error> {
+	let data = std::fs::read(path)?;
+	let input = &mut &data[..];
+	let invalid = |e: sp_runtime::codec::Error| {
+		format!("{} is not a binary block export: {}", path.display(), e)
+	};
+	let count = u64::decode(input).map_err(invalid)?;
+	(0..count)
+		.map(|_| SignedBlock::<Block>::decode(input).map_err(|e| invalid(e).into()))
+		.collect()
+}
+
+/// Execute a block on the current state and return the resulting state root. The seal is
+/// removed from the header first, like the import queue does before execution.
+fn execute(mut header: Header, extrinsics: Vec<UncheckedExtrinsic>) -> Result<Hash, String> {
+	header.digest_mut().logs.retain(|item| item.as_seal().is_none());
+
+	Executive::initialize_block(&header);
+	for (i, xt) in extrinsics.into_iter().enumerate() {
+		Executive::apply_extrinsic(xt)
+			.map_err(|e| format!("extrinsic {} is invalid: {:?}", i, e))?;
+	}
+	Ok(*Executive::finalize_block().state_root())
+}
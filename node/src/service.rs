@@ -1,8 +1,10 @@
 //! Service and ServiceFactory implementation. Specialized wrapper over substrate service.
 
+use futures::StreamExt;
 use node_template_runtime::{self, opaque::Block, RuntimeApi};
 use sc_client_api::ExecutorProvider;
 use sc_consensus_aura::{ImportQueueParams, SlotProportion, StartAuraParams};
+use sc_consensus_manual_seal::{consensus::aura::AuraConsensusDataProvider, EngineCommand};
 pub use sc_executor::NativeElseWasmExecutor;
 use sc_finality_grandpa::SharedVoterState;
 use sc_keystore::LocalKeystore;
@@ -10,7 +12,13 @@ use sc_service::{error::Error as ServiceError, Configuration, TaskManager};
 use sc_telemetry::{Telemetry, TelemetryWorker};
 use sp_consensus::SlotData;
 use sp_consensus_aura::sr25519::AuthorityPair as AuraPair;
-use std::{sync::Arc, time::Duration};
+use std::{
+	sync::{
+		atomic::{AtomicU64, Ordering},
+		Arc,
+	},
+	time::Duration,
+};
 
 // Our native executor instance.
 pub struct ExecutorDispatch;
@@ -18,10 +26,11 @@ pub struct ExecutorDispatch;
 impl sc_executor::NativeExecutionDispatch for ExecutorDispatch {
 	/// Only enable the benchmarking host functions when we actually want to benchmark.
 	#[cfg(feature = "runtime-benchmarks")]
-	type ExtendHostFunctions = frame_benchmarking::benchmarking::HostFunctions;
-	/// Otherwise we only use the default Substrate host functions.
+	type ExtendHostFunctions =
+		(frame_benchmarking::benchmarking::HostFunctions, node_template_hashing::HostFunctions);
+	/// Otherwise we only add our own host functions to the default Substrate ones.
 	#[cfg(not(feature = "runtime-benchmarks"))]
-	type ExtendHostFunctions = ();
+	type ExtendHostFunctions = node_template_hashing::HostFunctions;
 
 	fn dispatch(method: &str, data: &[u8]) -> Option<Vec<u8>> {
 		node_template_runtime::api::dispatch(method, data)
@@ -37,8 +46,13 @@ type FullClient =
 type FullBackend = sc_service::TFullBackend<Block>;
 type FullSelectChain = sc_consensus::LongestChain<FullBackend, Block>;
 
+/// Build the components shared by all node subcommands.
+///
+/// With `instant_seal`, blocks are imported without Aura verification, matching blocks authored
+/// by [`new_full`] in instant seal mode.
 pub fn new_partial(
 	config: &Configuration,
+	instant_seal: bool,
 ) -> Result<
 	sc_service::PartialComponents<
 		FullClient,
@@ -112,7 +126,13 @@ pub fn new_partial(
 
 	let slot_duration = sc_consensus_aura::slot_duration(&*client)?.slot_duration();
 
-	let import_queue =
+	let import_queue = if instant_seal {
+		sc_consensus_manual_seal::import_queue(
+			Box::new(client.clone()),
+			&task_manager.spawn_essential_handle(),
+			config.prometheus_registry(),
+		)
+	} else {
 		sc_consensus_aura::import_queue::<AuraPair, _, _, _, _, _, _>(ImportQueueParams {
 			block_import: grandpa_block_import.clone(),
 			justification_import: Some(Box::new(grandpa_block_import.clone())),
@@ -135,7 +155,8 @@ pub fn new_partial(
 			registry: config.prometheus_registry(),
 			check_for_equivocation: Default::default(),
 			telemetry: telemetry.as_ref().map(|x| x.handle()),
-		})?;
+		})?
+	};
 
 	Ok(sc_service::PartialComponents {
 		client,
@@ -157,7 +178,13 @@ fn remote_keystore(_url: &String) -> Result<Arc<LocalKeystore>, &'static str> {
 }
 
 /// Builds a new service for a full client.
-pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError> {
+///
+/// With `instant_seal`, the node authors and finalizes a block whenever a transaction enters the
+/// pool or the `engine_createBlock` RPC is called, instead of running Aura and GRANDPA.
+pub fn new_full(
+	mut config: Configuration,
+	instant_seal: bool,
+) -> Result<TaskManager, ServiceError> {
 	let sc_service::PartialComponents {
 		client,
 		backend,
@@ -167,7 +194,7 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 		select_chain,
 		transaction_pool,
 		other: (block_import, grandpa_link, mut telemetry),
-	} = new_partial(&config)?;
+	} = new_partial(&config, instant_seal)?;
 
 	if let Some(url) = &config.keystore_remote {
 		match remote_keystore(url) {
@@ -214,13 +241,24 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 	let enable_grandpa = !config.disable_grandpa;
 	let prometheus_registry = config.prometheus_registry().cloned();
 
+	let (command_sink, commands_stream) = if instant_seal {
+		let (sink, stream) = futures::channel::mpsc::channel(1024);
+		(Some(sink), Some(stream))
+	} else {
+		(None, None)
+	};
+
 	let rpc_extensions_builder = {
 		let client = client.clone();
 		let pool = transaction_pool.clone();
 
 		Box::new(move |deny_unsafe, _| {
-			let deps =
-				crate::rpc::FullDeps { client: client.clone(), pool: pool.clone(), deny_unsafe };
+			let deps = crate::rpc::FullDeps {
+				client: client.clone(),
+				pool: pool.clone(),
+				deny_unsafe,
+				command_sink: command_sink.clone(),
+			};
 
 			Ok(crate::rpc::create_full(deps))
 		})
@@ -239,6 +277,80 @@ pub fn new_full(mut config: Configuration) -> Result<TaskManager, ServiceError>
 		telemetry: telemetry.as_mut(),
 	})?;
 
+	if let Some(registry) = prometheus_registry.as_ref() {
+		let metrics = crate::metrics::BlockWeightMetrics::register(registry)
+			.map_err(ServiceError::Prometheus)?;
+		task_manager.spawn_handle().spawn(
+			"block-weight-metrics",
+			None,
+			metrics.run(client.clone()),
+		);
+	}
+
+	if let Some(commands_stream) = commands_stream {
+		let proposer_factory = sc_basic_authorship::ProposerFactory::new(
+			task_manager.spawn_handle(),
+			client.clone(),
+			transaction_pool.clone(),
+			prometheus_registry.as_ref(),
+			telemetry.as_ref().map(|x| x.handle()),
+		);
+
+		// Seal a block for every transaction entering the pool, in addition to the blocks
+		// requested through RPC.
+		let pool_stream =
+			transaction_pool.pool().validated_pool().import_notification_stream().map(|_| {
+				EngineCommand::SealNewBlock {
+					create_empty: false,
+					finalize: true,
+					parent_hash: None,
+					sender: None,
+				}
+			});
+
+		// Aura requires every block to be in a later slot than its parent, which blocks sealed
+		// in quick succession are not in real time, so advance the timestamp by at least one
+		// slot per block.
+		let slot_duration = sc_consensus_aura::slot_duration(&*client)?.slot_duration();
+		let last_timestamp = Arc::new(AtomicU64::new(0));
+
+		let manual_seal =
+			sc_consensus_manual_seal::run_manual_seal(sc_consensus_manual_seal::ManualSealParams {
+				block_import: client.clone(),
+				env: proposer_factory,
+				client: client.clone(),
+				pool: transaction_pool,
+				commands_stream: futures::stream::select(commands_stream, pool_stream),
+				select_chain,
+				consensus_data_provider: Some(Box::new(AuraConsensusDataProvider::new(
+					client.clone(),
+				))),
+				create_inherent_data_providers: move |_, ()| {
+					let last_timestamp = last_timestamp.clone();
+					async move {
+						let now = *sp_timestamp::Timestamp::current();
+						let next = last_timestamp.load(Ordering::SeqCst) +
+							slot_duration.as_millis() as u64;
+						let timestamp = now.max(next);
+						last_timestamp.store(timestamp, Ordering::SeqCst);
+
+						Ok(sp_timestamp::InherentDataProvider::new(timestamp.into()))
+					}
+				},
+			});
+
+		// the sealing task is considered essential, i.e. if it
+		// fails we take down the service with it.
+		task_manager.spawn_essential_handle().spawn_blocking(
+			"instant-seal",
+			Some("block-authoring"),
+			manual_seal,
+		);
+
+		network_starter.start_network();
+		return Ok(task_manager)
+	}
+
 	if role.is_authority() {
 		let proposer_factory = sc_basic_authorship::ProposerFactory::new(
 			task_manager.spawn_handle(),
@@ -0,0 +1,66 @@
+//! The `generate-session-keys` subcommand.
+
+use node_template_runtime::opaque::SessionKeys;
+use sc_cli::{Error, KeystoreParams, SharedParams, SubstrateCli};
+use sc_keystore::LocalKeystore;
+use sc_service::{config::KeystoreConfig, BasePath};
+use sp_core::{
+	crypto::{key_types, Ss58Codec},
+	hexdisplay::HexDisplay,
+};
+use sp_keystore::{SyncCryptoStore, SyncCryptoStorePtr};
+use sp_runtime::codec::Encode;
+use std::sync::Arc;
+use structopt::StructOpt;
+
+/// The `generate-session-keys` command.
+///
+/// Generates an Aura and a GRANDPA key, inserts both into the keystore of the node and prints
+/// the encoded session keys for registration on chain. This is what the `author_rotateKeys` RPC
+/// does, but it works while the node is stopped.
+#[derive(Debug, StructOpt)]
+pub struct GenerateSessionKeysCmd {
+	/// Derive both keys from this secret URI instead of generating random ones.
+	#[structopt(long)]
+	pub suri: Option<String>,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub keystore_params: KeystoreParams,
+}
+
+impl GenerateSessionKeysCmd {
+	/// Run the command.
+	pub fn run<C: SubstrateCli>(&self, cli: &C) -> Result<(), Error> {
+		let base_path = self
+			.shared_params
+			.base_path()
+			.unwrap_or_else(|| BasePath::from_project("", "", &C::executable_name()));
+		let chain_id = self.shared_params.chain_id(self.shared_params.is_dev());
+		let chain_spec = cli.load_spec(&chain_id)?;
+		let config_dir = base_path.config_dir(chain_spec.id());
+
+		let keystore: SyncCryptoStorePtr =
+			match self.keystore_params.keystore_config(&config_dir)? {
+				(_, KeystoreConfig::Path { path, password }) =>
+					Arc::new(LocalKeystore::open(path, password)?),
+				_ => unreachable!("keystore_config always returns path and password; qed"),
+			};
+
+		let suri = self.suri.as_deref();
+		let aura = SyncCryptoStore::sr25519_generate_new(&*keystore, key_types::AURA, suri)
+			.map_err(|_| Error::KeyStoreOperation)?;
+		let grandpa = SyncCryptoStore::ed25519_generate_new(&*keystore, key_types::GRANDPA, suri)
+			.map_err(|_| Error::KeyStoreOperation)?;
+		let keys = SessionKeys { aura: aura.into(), grandpa: grandpa.into() };
+
+		println!("Aura:         {}", aura.to_ss58check());
+		println!("GRANDPA:      {}", grandpa.to_ss58check());
+		println!("Session keys: 0x{}", HexDisplay::from(&keys.encode()));
+		Ok(())
+	}
+}
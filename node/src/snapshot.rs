@@ -0,0 +1,75 @@
+//! The `restore-state` subcommand.
+
+use node_template_runtime::Block;
+use sc_cli::{CliConfiguration, DatabaseParams, Error, PruningParams, SharedParams};
+use sc_service::Configuration;
+use sp_blockchain::HeaderBackend;
+use std::{path::PathBuf, sync::Arc};
+use structopt::StructOpt;
+
+/// The `restore-state` command.
+///
+/// Creates the database of a node with the state of a snapshot written by `snapshot-state`, the
+/// alias of `export-state`, as genesis. Start the node with `--chain` set to the snapshot
+/// afterwards. The copy keeps the authorities of the source network, so it only produces blocks
+/// with their keys in its keystore.
+#[derive(Debug, StructOpt)]
+pub struct RestoreStateCmd {
+	/// The snapshot written by `snapshot-state`.
+	#[structopt(parse(from_os_str))]
+	pub snapshot: PathBuf,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub shared_params: SharedParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub pruning_params: PruningParams,
+
+	#[allow(missing_docs)]
+	#[structopt(flatten)]
+	pub database_params: DatabaseParams,
+}
+
+impl RestoreStateCmd {
+	/// Fail if the node configured by `config` already has a database, which would be kept
+	/// instead of the snapshot.
+	pub fn check_empty(&self, config: &Configuration) -> Result<(), Error> {
+		match config.database.path() {
+			Some(path) if path.exists() => Err(Error::Input(format!(
+				"{} already holds a database, remove it with `purge-chain` first",
+				path.display()
+			))),
+			_ => Ok(()),
+		}
+	}
+
+	/// Run the command on the client created for the snapshot.
+	pub fn run<C: HeaderBackend<Block>>(&self, client: Arc<C>) -> Result<(), Error> {
+		println!(
+			"Database created with genesis {}, start the node with `--chain {}`",
+			client.info().genesis_hash,
+			self.snapshot.display()
+		);
+		Ok(())
+	}
+}
+
+impl CliConfiguration for RestoreStateCmd {
+	fn shared_params(&self) -> &SharedParams {
+		&self.shared_params
+	}
+
+	fn pruning_params(&self) -> Option<&PruningParams> {
+		Some(&self.pruning_params)
+	}
+
+	fn database_params(&self) -> Option<&DatabaseParams> {
+		Some(&self.database_params)
+	}
+
+	fn chain_id(&self, _is_dev: bool) -> Result<String, Error> {
+		Ok(self.snapshot.to_string_lossy().into_owned())
+	}
+}
@@ -0,0 +1,80 @@
+//! The `upgrade-runtime` subcommand.
+
+use jsonrpc_core_client::transports::http;
+use node_template_runtime::{Call, Hash};
+use sc_cli::Error;
+use sc_executor_common::runtime_blob::RuntimeBlob;
+use sc_rpc_api::state::StateClient;
+use sp_api::RuntimeVersion;
+use sp_core::{blake2_256, hexdisplay::HexDisplay};
+use sp_runtime::codec::Encode;
+use std::path::PathBuf;
+use structopt::StructOpt;
+
+/// The `upgrade-runtime` command.
+///
+/// Checks that a runtime Wasm blob is an upgrade of the runtime a node of the target chain
+/// currently runs and prints the call that installs it, for submission by the sudo key.
+#[derive(Debug, StructOpt)]
+pub struct UpgradeRuntimeCmd {
+	/// The compact (and optionally compressed) runtime Wasm blob.
+	#[structopt(parse(from_os_str))]
+	pub wasm: PathBuf,
+
+	/// The HTTP RPC endpoint of a node of the chain to upgrade.
+	#[structopt(long, default_value = "http://localhost:9933")]
+	pub url: String,
+}
+
+impl UpgradeRuntimeCmd {
+	/// The version of the runtime at the best block of the node at `url`.
+	fn on_chain_version(&self) -> Result<RuntimeVersion, Error> {
+		let runtime = tokio::runtime::Builder::new_current_thread().enable_all().build()?;
+		runtime.block_on(async {
+			let client: StateClient<Hash> = http::connect(&self.url)
+				.await
+				.map_err(|e| format!("Error connecting to `{}`: {}", self.url, e))?;
+			let version = client
+				.runtime_version(None)
+				.await
+				.map_err(|e| format!("Error querying the runtime version: {}", e))?;
+			Ok(version)
+		})
+	}
+
+	/// Run the command.
+	pub fn run(&self) -> Result<(), Error> {
+		let code = std::fs::read(&self.wasm)?;
+		let blob = RuntimeBlob::uncompress_if_needed(&code)
+			.map_err(|e| format!("Invalid runtime blob: {}", e))?;
+		let version = sc_executor::read_embedded_version(&blob)
+			.map_err(|e| format!("Error reading runtime version: {}", e))?
+			.ok_or_else(|| "The runtime blob does not embed its version".to_string())?;
+
+		let current = self.on_chain_version()?;
+		if version.spec_name != current.spec_name {
+			return Err(format!(
+				"The runtime blob is `{}`, but the chain runs `{}`",
+				version.spec_name, current.spec_name
+			)
+			.into())
+		}
+		if version.spec_version <= current.spec_version {
+			return Err(format!(
+				"The runtime blob has spec_version {}, which does not upgrade {}",
+				version.spec_version, current.spec_version
+			)
+			.into())
+		}
+
+		// `set_code` is weighed as a full block, which `sudo` charges in full.
+		let call = Call::Sudo(pallet_sudo::Call::sudo {
+			call: Box::new(Call::System(frame_system::Call::set_code { code: code.clone() })),
+		});
+
+		println!("spec_version: {} -> {}", current.spec_version, version.spec_version);
+		println!("Code hash:    0x{}", HexDisplay::from(&blake2_256(&code)));
+		println!("Call:         0x{}", HexDisplay::from(&call.encode()));
+		Ok(())
+	}
+}
@@ -0,0 +1,23 @@
+//! Benchmarking setup for pallet-block-metrics
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_support::traits::Hooks;
+
+benchmarks! {
+	deposit_usage {
+		let n = frame_system::Pallet::<T>::block_number();
+	}: {
+		Pallet::<T>::on_initialize(n);
+		Pallet::<T>::on_finalize(n);
+	}
+	verify {
+		let digest = frame_system::Pallet::<T>::digest();
+		assert!(digest.logs.iter().any(|item| {
+			item.as_other().and_then(WeightUsage::from_digest_data).is_some()
+		}));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
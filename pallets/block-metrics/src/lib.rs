@@ -0,0 +1,91 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Block fullness reported in the header.
+///
+/// When a block is finalized, the pallet adds a [`WeightUsage`] digest item with the weight
+/// consumed by each dispatch class to the block header. Nodes and analytics services read it
+/// from the header to chart block fullness without re-executing blocks. The node exports it to
+/// Prometheus.
+pub use pallet::*;
+
+use codec::{Decode, Encode};
+use frame_support::{weights::Weight, RuntimeDebug};
+use sp_std::prelude::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// The prefix telling the `Other` digest items of this pallet apart from others.
+pub const DIGEST_PREFIX: [u8; 4] = *b"wght";
+
+/// The weight consumed by a block, per dispatch class.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug)]
+pub struct WeightUsage {
+	/// The weight of normal dispatches.
+	pub normal: Weight,
+	/// The weight of operational dispatches.
+	pub operational: Weight,
+	/// The weight of mandatory dispatches and hooks.
+	pub mandatory: Weight,
+}
+
+impl WeightUsage {
+	/// The data of the `Other` digest item reporting this usage.
+	pub fn to_digest_data(&self) -> Vec<u8> {
+		(DIGEST_PREFIX, self).encode()
+	}
+
+	/// Decode the usage from the data of an `Other` digest item, if it reports one.
+	pub fn from_digest_data(mut data: &[u8]) -> Option<Self> {
+		match <([u8; 4], Self)>::decode(&mut data) {
+			Ok((DIGEST_PREFIX, usage)) => Some(usage),
+			_ => None,
+		}
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::{WeightInfo, WeightUsage};
+	use frame_support::{pallet_prelude::*, weights::DispatchClass};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::generic::DigestItem;
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Weight information for the hooks of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			// Reading the block weight and depositing the digest in `on_finalize`.
+			T::WeightInfo::deposit_usage()
+		}
+
+		fn on_finalize(_n: T::BlockNumber) {
+			let consumed = frame_system::Pallet::<T>::block_weight();
+			let usage = WeightUsage {
+				normal: *consumed.get(DispatchClass::Normal),
+				operational: *consumed.get(DispatchClass::Operational),
+				mandatory: *consumed.get(DispatchClass::Mandatory),
+			};
+			frame_system::Pallet::<T>::deposit_log(DigestItem::Other(usage.to_digest_data()));
+		}
+	}
+}
@@ -0,0 +1,33 @@
+use crate::{mock::*, WeightUsage};
+use frame_support::{traits::Hooks, weights::DispatchClass};
+use sp_runtime::generic::DigestItem;
+
+/// The usages reported in the digest of the current block.
+fn reported() -> Vec<WeightUsage> {
+	System::digest()
+		.logs
+		.iter()
+		.filter_map(|item| match item {
+			DigestItem::Other(data) => WeightUsage::from_digest_data(data),
+			_ => None,
+		})
+		.collect()
+}
+
+#[test]
+fn weight_usage_is_reported_in_the_digest() {
+	new_test_ext().execute_with(|| {
+		System::register_extra_weight_unchecked(100, DispatchClass::Normal);
+		System::register_extra_weight_unchecked(20, DispatchClass::Mandatory);
+		BlockMetrics::on_finalize(1);
+
+		assert_eq!(reported(), vec![WeightUsage { normal: 100, operational: 0, mandatory: 20 }]);
+	});
+}
+
+#[test]
+fn other_digest_items_are_ignored() {
+	assert_eq!(WeightUsage::from_digest_data(b"not a usage"), None);
+	let usage = WeightUsage { normal: 1, operational: 2, mandatory: 3 };
+	assert_eq!(WeightUsage::from_digest_data(&usage.to_digest_data()), Some(usage));
+}
@@ -0,0 +1,25 @@
+//! Benchmarking setup for pallet-block-times
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_support::traits::{Get, Hooks, Time};
+use sp_runtime::traits::Saturating;
+
+benchmarks! {
+	checkpoint {
+		// The first checkpoint block pruning an old checkpoint.
+		let interval = T::CheckpointInterval::get();
+		let retention = interval.saturating_mul(T::MaxCheckpoints::get().into());
+		let n = retention.max(interval);
+		Checkpoints::<T>::insert(n.saturating_sub(retention), T::Time::now());
+	}: {
+		Pallet::<T>::on_initialize(n);
+		Pallet::<T>::on_finalize(n);
+	}
+	verify {
+		assert!(Checkpoints::<T>::contains_key(n));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,115 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Wall-clock times of past blocks.
+///
+/// Every `CheckpointInterval` blocks, the pallet records the time set by the timestamp
+/// inherent. [`Pallet::time_at`] answers with the recorded time of a checkpoint block and
+/// interpolates between the two surrounding checkpoints for the blocks in between, so
+/// certificates can quote an approximate real-world time for the block an entry was registered
+/// in. The last `MaxCheckpoints` checkpoints are kept.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::WeightInfo;
+	use frame_support::{pallet_prelude::*, traits::Time};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, UniqueSaturatedFrom, UniqueSaturatedInto, Zero};
+
+	pub type MomentOf<T> = <<T as Config>::Time as Time>::Moment;
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The source of the block times, usually the timestamp pallet.
+		type Time: Time;
+
+		/// The number of blocks between two checkpoints. Zero disables checkpoints.
+		#[pallet::constant]
+		type CheckpointInterval: Get<Self::BlockNumber>;
+
+		/// The number of checkpoints kept. Zero keeps them forever.
+		#[pallet::constant]
+		type MaxCheckpoints: Get<u32>;
+
+		/// Weight information for the hooks of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The time of every recent checkpoint block.
+	#[pallet::storage]
+	#[pallet::getter(fn checkpoints)]
+	pub type Checkpoints<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, MomentOf<T>>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			if Self::is_checkpoint(n) {
+				// Reading the time, writing the checkpoint and pruning an old one in
+				// `on_finalize`.
+				T::WeightInfo::checkpoint()
+			} else {
+				0
+			}
+		}
+
+		fn on_finalize(n: T::BlockNumber) {
+			if !Self::is_checkpoint(n) {
+				return
+			}
+			Checkpoints::<T>::insert(n, T::Time::now());
+
+			let retention =
+				T::CheckpointInterval::get().saturating_mul(T::MaxCheckpoints::get().into());
+			if !retention.is_zero() && n >= retention {
+				Checkpoints::<T>::remove(n.saturating_sub(retention));
+			}
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether a checkpoint is recorded at block `n`.
+		fn is_checkpoint(n: T::BlockNumber) -> bool {
+			let interval = T::CheckpointInterval::get();
+			!interval.is_zero() && (n % interval).is_zero()
+		}
+
+		/// The approximate time of block `n`, or `None` if it is not covered by the kept
+		/// checkpoints, for example because it is newer than the last one.
+		pub fn time_at(n: T::BlockNumber) -> Option<MomentOf<T>> {
+			let interval = T::CheckpointInterval::get();
+			if interval.is_zero() {
+				return None
+			}
+
+			let before = n - n % interval;
+			let at_before = Checkpoints::<T>::get(before)?;
+			if before == n {
+				return Some(at_before)
+			}
+			let at_after = Checkpoints::<T>::get(before + interval)?;
+
+			let elapsed: u128 = at_after.saturating_sub(at_before).unique_saturated_into();
+			let blocks: u128 = (n - before).unique_saturated_into();
+			let interval: u128 = interval.unique_saturated_into();
+			let offset = MomentOf::<T>::unique_saturated_from(elapsed * blocks / interval);
+			Some(at_before.saturating_add(offset))
+		}
+	}
+}
@@ -0,0 +1,43 @@
+use crate::mock::*;
+use frame_support::traits::Hooks;
+
+/// Finalize blocks up to `n`, with six seconds between blocks.
+fn run_to_block(n: u64) {
+	for block in System::block_number()..=n {
+		System::set_block_number(block);
+		Timestamp::set_timestamp(block * 6_000);
+		BlockTimes::on_finalize(block);
+	}
+}
+
+#[test]
+fn checkpoints_are_recorded_every_interval() {
+	new_test_ext().execute_with(|| {
+		run_to_block(25);
+		assert_eq!(BlockTimes::checkpoints(10), Some(60_000));
+		assert_eq!(BlockTimes::checkpoints(20), Some(120_000));
+		assert_eq!(BlockTimes::checkpoints(15), None);
+	});
+}
+
+#[test]
+fn times_between_checkpoints_are_interpolated() {
+	new_test_ext().execute_with(|| {
+		run_to_block(25);
+		assert_eq!(BlockTimes::time_at(10), Some(60_000));
+		assert_eq!(BlockTimes::time_at(13), Some(78_000));
+		// Block 25 is newer than the last checkpoint, block 5 older than the first.
+		assert_eq!(BlockTimes::time_at(25), None);
+		assert_eq!(BlockTimes::time_at(5), None);
+	});
+}
+
+#[test]
+fn old_checkpoints_are_pruned() {
+	new_test_ext().execute_with(|| {
+		run_to_block(40);
+		assert_eq!(BlockTimes::checkpoints(10), None);
+		assert_eq!(BlockTimes::time_at(15), None);
+		assert_eq!(BlockTimes::time_at(35), Some(210_000));
+	});
+}
@@ -0,0 +1,40 @@
+//! Placeholder weights for pallet_block_times
+//!
+//! NOT GENERATED: these are hand-written estimates in the format of the benchmark CLI, not
+//! measurements. Replace this file with the output of `node-template benchmark-all` on reference
+//! hardware before a release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_block_times.
+pub trait WeightInfo {
+	fn checkpoint() -> Weight;
+}
+
+/// Placeholder weights for pallet_block_times, priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: BlockTimes Checkpoints (r:0 w:2)
+	fn checkpoint() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: BlockTimes Checkpoints (r:0 w:2)
+	fn checkpoint() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+}
@@ -0,0 +1,61 @@
+//! Benchmarking setup for pallet-claims
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::{Currency, EnsureOrigin};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+fn secret_key(seed: u32) -> libsecp256k1::SecretKey {
+	libsecp256k1::SecretKey::parse(&keccak_256(&seed.encode())).unwrap()
+}
+
+fn eth_address(secret: &libsecp256k1::SecretKey) -> EthereumAddress {
+	let public = libsecp256k1::PublicKey::from_secret_key(secret);
+	let mut address = EthereumAddress::default();
+	address.0.copy_from_slice(&keccak_256(&public.serialize()[1..65])[12..]);
+	address
+}
+
+/// Sign the claim message for `dest`, with `extra` appended, using `secret`.
+fn eth_sign<T: Config>(
+	secret: &libsecp256k1::SecretKey,
+	dest: &T::AccountId,
+	extra: &[u8],
+) -> EcdsaSignature {
+	let what = dest.using_encoded(to_ascii_hex);
+	let msg = keccak_256(&Pallet::<T>::ethereum_signable_message(&what, extra));
+	let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), secret);
+	let mut r = [0u8; 65];
+	r[0..64].copy_from_slice(&sig.serialize()[..]);
+	r[64] = recovery_id.serialize();
+	EcdsaSignature(r)
+}
+
+benchmarks! {
+	// The worst case requires the statement, in which case the signer is recovered twice.
+	claim {
+		let secret = secret_key(0);
+		let address = eth_address(&secret);
+		let dest: T::AccountId = account("dest", 0, SEED);
+		let value = T::Currency::minimum_balance().max(1u32.into()) * 10u32.into();
+		Pallet::<T>::mint_claim(T::MintOrigin::successful_origin(), address, value, true)?;
+		let signature = eth_sign::<T>(&secret, &dest, T::Statement::get());
+	}: _(RawOrigin::None, dest, signature)
+	verify {
+		assert!(!Claims::<T>::contains_key(&address));
+	}
+
+	mint_claim {
+		let origin = T::MintOrigin::successful_origin();
+		let address = eth_address(&secret_key(0));
+		let value = T::Currency::minimum_balance().max(1u32.into()) * 10u32.into();
+	}: _<T::Origin>(origin, address, value, true)
+	verify {
+		assert!(Claims::<T>::contains_key(&address));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
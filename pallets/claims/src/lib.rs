@@ -0,0 +1,292 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Genesis token distribution to holders of Ethereum addresses.
+///
+/// Balances are assigned to Ethereum addresses at genesis (or later by `MintOrigin`). The
+/// holder of an address claims by signing, with their Ethereum key, a message naming the
+/// destination account. Claims are submitted as unsigned transactions so that destination
+/// accounts do not need funds to pay fees. Addresses can be required to additionally agree to
+/// `Statement`, in which case the statement is appended to the signed message.
+pub use pallet::*;
+
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+use sp_io::{crypto::secp256k1_ecdsa_recover, hashing::keccak_256};
+use sp_std::prelude::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// An Ethereum address, i.e. the last 20 bytes of the Keccak-256 hash of a public key.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct EthereumAddress(pub [u8; 20]);
+
+/// A recoverable secp256k1 signature as produced by `eth_sign`.
+#[derive(Encode, Decode, Clone, TypeInfo)]
+pub struct EcdsaSignature(pub [u8; 65]);
+
+impl PartialEq for EcdsaSignature {
+	fn eq(&self, other: &Self) -> bool {
+		self.0[..] == other.0[..]
+	}
+}
+
+impl Eq for EcdsaSignature {}
+
+impl sp_std::fmt::Debug for EcdsaSignature {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter<'_>) -> sp_std::fmt::Result {
+		write!(f, "EcdsaSignature({:?})", &self.0[..])
+	}
+}
+
+/// Encode `data` as lowercase hexadecimal ASCII.
+pub fn to_ascii_hex(data: &[u8]) -> Vec<u8> {
+	let mut r = Vec::with_capacity(data.len() * 2);
+	let mut push_nibble = |n| r.push(if n < 10 { b'0' + n } else { b'a' - 10 + n });
+	for &b in data.iter() {
+		push_nibble(b / 16);
+		push_nibble(b % 16);
+	}
+	r
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::{pallet_prelude::*, traits::Currency};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, Zero};
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency in which claims are paid out.
+		type Currency: Currency<Self::AccountId>;
+
+		/// The origin allowed to assign new claims after genesis.
+		type MintOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The text prepended to the destination account in the signed message.
+		#[pallet::constant]
+		type Prefix: Get<&'static [u8]>;
+
+		/// The statement that addresses flagged at minting must agree to.
+		#[pallet::constant]
+		type Statement: Get<&'static [u8]>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The unclaimed balance of every Ethereum address.
+	#[pallet::storage]
+	#[pallet::getter(fn claims)]
+	pub type Claims<T: Config> = StorageMap<_, Identity, EthereumAddress, BalanceOf<T>>;
+
+	/// The addresses that have to sign `Statement` in order to claim.
+	#[pallet::storage]
+	#[pallet::getter(fn statement_required)]
+	pub type StatementRequired<T> = StorageMap<_, Identity, EthereumAddress, bool, ValueQuery>;
+
+	/// The total of all unclaimed balances.
+	#[pallet::storage]
+	#[pallet::getter(fn total)]
+	pub type Total<T: Config> = StorageValue<_, BalanceOf<T>, ValueQuery>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// The initial claims as `(address, balance, statement_required)`.
+		pub claims: Vec<(EthereumAddress, BalanceOf<T>, bool)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { claims: Vec::new() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (address, value, statement_required) in &self.claims {
+				assert!(
+					*value >= T::Currency::minimum_balance(),
+					"Claims below the existential deposit cannot be paid out"
+				);
+				Pallet::<T>::add_claim(*address, *value, *statement_required);
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// Tokens were claimed. [who, ethereum_address, amount]
+		Claimed(T::AccountId, EthereumAddress, BalanceOf<T>),
+		/// A claim was assigned to an address. [ethereum_address, amount]
+		ClaimMinted(EthereumAddress, BalanceOf<T>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The signature does not belong to an address with a claim, or does not cover the
+		/// statement the address has to agree to.
+		InvalidEthereumSignature,
+		/// The address already has a claim.
+		AlreadyHasClaim,
+		/// The claim is below the existential deposit, so paying it out would burn it.
+		ClaimBelowMinimum,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pay the claim of the Ethereum address that signed `dest` out to `dest`.
+		///
+		/// The dispatch origin must be none; validity is established by `ValidateUnsigned`.
+		/// The weight covers recovering the signer twice, once without and once with
+		/// `Statement`.
+		#[pallet::weight(T::WeightInfo::claim())]
+		pub fn claim(
+			origin: OriginFor<T>,
+			dest: T::AccountId,
+			ethereum_signature: EcdsaSignature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+
+			let signer = Self::recover_claimant(&dest, &ethereum_signature)
+				.ok_or(Error::<T>::InvalidEthereumSignature)?;
+			let value = Claims::<T>::take(&signer).ok_or(Error::<T>::InvalidEthereumSignature)?;
+			StatementRequired::<T>::remove(&signer);
+			Total::<T>::mutate(|total| *total = total.saturating_sub(value));
+			T::Currency::deposit_creating(&dest, value);
+
+			Self::deposit_event(Event::Claimed(dest, signer, value));
+			Ok(())
+		}
+
+		/// Assign `value` to `who`, optionally requiring the holder to agree to `Statement`.
+		/// `value` must be at least the existential deposit.
+		///
+		/// The dispatch origin must be `MintOrigin`.
+		#[pallet::weight(T::WeightInfo::mint_claim())]
+		pub fn mint_claim(
+			origin: OriginFor<T>,
+			who: EthereumAddress,
+			value: BalanceOf<T>,
+			statement_required: bool,
+		) -> DispatchResult {
+			T::MintOrigin::ensure_origin(origin)?;
+
+			ensure!(!Claims::<T>::contains_key(&who), Error::<T>::AlreadyHasClaim);
+			ensure!(value >= T::Currency::minimum_balance(), Error::<T>::ClaimBelowMinimum);
+			Self::add_claim(who, value, statement_required);
+
+			Self::deposit_event(Event::ClaimMinted(who, value));
+			Ok(())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			const PRIORITY: u64 = 100;
+
+			let signer = match call {
+				Call::claim { dest, ethereum_signature } =>
+					Self::recover_claimant(dest, ethereum_signature)
+						.ok_or(InvalidTransaction::BadProof)?,
+				_ => return Err(InvalidTransaction::Call.into()),
+			};
+
+			ValidTransaction::with_tag_prefix("Claims")
+				.priority(PRIORITY)
+				.and_provides(signer)
+				.longevity(TransactionLongevity::max_value())
+				.propagate(true)
+				.build()
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		fn add_claim(who: EthereumAddress, value: BalanceOf<T>, statement_required: bool) {
+			Claims::<T>::insert(&who, value);
+			if statement_required {
+				StatementRequired::<T>::insert(&who, true);
+			}
+			Total::<T>::mutate(|total| *total = total.saturating_add(value));
+		}
+
+		/// The address with an outstanding claim that signed `dest`, if any.
+		///
+		/// Addresses flagged with `StatementRequired` only match signatures covering the
+		/// statement.
+		pub fn recover_claimant(
+			dest: &T::AccountId,
+			signature: &EcdsaSignature,
+		) -> Option<EthereumAddress> {
+			let data = dest.using_encoded(to_ascii_hex);
+			let has_claim = |who: &EthereumAddress| {
+				Claims::<T>::get(who).map_or(false, |value| !value.is_zero())
+			};
+
+			Self::eth_recover(signature, &data, &[])
+				.filter(|who| has_claim(who) && !StatementRequired::<T>::get(who))
+				.or_else(|| {
+					Self::eth_recover(signature, &data, T::Statement::get())
+						.filter(|who| has_claim(who) && StatementRequired::<T>::get(who))
+				})
+		}
+
+		/// The message an Ethereum wallet signs for `eth_sign`, given the signed data.
+		pub fn ethereum_signable_message(what: &[u8], extra: &[u8]) -> Vec<u8> {
+			let prefix = T::Prefix::get();
+			let mut l = prefix.len() + what.len() + extra.len();
+			let mut rev = Vec::new();
+			while l > 0 {
+				rev.push(b'0' + (l % 10) as u8);
+				l /= 10;
+			}
+			let mut v = b"\x19Ethereum Signed Message:\n".to_vec();
+			v.extend(rev.into_iter().rev());
+			v.extend_from_slice(prefix);
+			v.extend_from_slice(what);
+			v.extend_from_slice(extra);
+			v
+		}
+
+		/// Recover the Ethereum address that signed `what` followed by `extra`.
+		fn eth_recover(s: &EcdsaSignature, what: &[u8], extra: &[u8]) -> Option<EthereumAddress> {
+			let msg = keccak_256(&Self::ethereum_signable_message(what, extra));
+			let public = secp256k1_ecdsa_recover(&s.0, &msg).ok()?;
+			let mut res = EthereumAddress::default();
+			res.0.copy_from_slice(&keccak_256(&public[..])[12..]);
+			Some(res)
+		}
+	}
+}
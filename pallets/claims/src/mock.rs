@@ -0,0 +1,128 @@
+use crate as pallet_claims;
+use crate::{to_ascii_hex, EcdsaSignature, EthereumAddress};
+use codec::Encode;
+use frame_support::parameter_types;
+use frame_system as system;
+use sp_core::H256;
+use sp_io::hashing::keccak_256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		Balances: pallet_balances::{Pallet, Call, Storage, Config<T>, Event<T>},
+		ClaimsModule: pallet_claims::{Pallet, Call, Storage, Config<T>, Event<T>, ValidateUnsigned},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = pallet_balances::AccountData<u64>;
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+parameter_types! {
+	pub const ExistentialDeposit: u64 = 1;
+}
+
+impl pallet_balances::Config for Test {
+	type MaxLocks = ();
+	type MaxReserves = ();
+	type ReserveIdentifier = [u8; 8];
+	type Balance = u64;
+	type Event = Event;
+	type DustRemoval = ();
+	type ExistentialDeposit = ExistentialDeposit;
+	type AccountStore = System;
+	type WeightInfo = ();
+}
+
+parameter_types! {
+	pub Prefix: &'static [u8] = b"Pay TEST tokens to the account:";
+	pub Statement: &'static [u8] = b"I agree to the terms of the distribution.";
+}
+
+impl pallet_claims::Config for Test {
+	type Event = Event;
+	type Currency = Balances;
+	type MintOrigin = frame_system::EnsureRoot<u64>;
+	type Prefix = Prefix;
+	type Statement = Statement;
+	type WeightInfo = ();
+}
+
+pub fn alice() -> libsecp256k1::SecretKey {
+	libsecp256k1::SecretKey::parse(&keccak_256(b"Alice")).unwrap()
+}
+
+pub fn bob() -> libsecp256k1::SecretKey {
+	libsecp256k1::SecretKey::parse(&keccak_256(b"Bob")).unwrap()
+}
+
+/// The Ethereum address of `secret`.
+pub fn eth(secret: &libsecp256k1::SecretKey) -> EthereumAddress {
+	let public = libsecp256k1::PublicKey::from_secret_key(secret);
+	let mut res = EthereumAddress::default();
+	res.0.copy_from_slice(&keccak_256(&public.serialize()[1..65])[12..]);
+	res
+}
+
+/// Sign the claim message for `dest`, with `extra` appended, using `secret`.
+pub fn sig(secret: &libsecp256k1::SecretKey, dest: u64, extra: &[u8]) -> EcdsaSignature {
+	let what = dest.using_encoded(to_ascii_hex);
+	let msg = keccak_256(&ClaimsModule::ethereum_signable_message(&what, extra));
+	let (sig, recovery_id) = libsecp256k1::sign(&libsecp256k1::Message::parse(&msg), secret);
+	let mut r = [0u8; 65];
+	r[0..64].copy_from_slice(&sig.serialize()[..]);
+	r[64] = recovery_id.serialize();
+	EcdsaSignature(r)
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	let mut t = system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_claims::GenesisConfig::<Test> {
+		claims: vec![(eth(&alice()), 100, false), (eth(&bob()), 200, true)],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
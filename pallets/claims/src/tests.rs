@@ -0,0 +1,110 @@
+use crate::{mock::*, Call as ClaimsCall, EcdsaSignature, Error};
+use frame_support::{assert_noop, assert_ok, unsigned::ValidateUnsigned};
+use sp_runtime::{
+	traits::BadOrigin,
+	transaction_validity::{InvalidTransaction, TransactionSource},
+};
+
+#[test]
+fn genesis_claims_are_recorded() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(ClaimsModule::claims(eth(&alice())), Some(100));
+		assert_eq!(ClaimsModule::claims(eth(&bob())), Some(200));
+		assert!(ClaimsModule::statement_required(eth(&bob())));
+		assert_eq!(ClaimsModule::total(), 300);
+	});
+}
+
+#[test]
+fn claiming_pays_destination() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ClaimsModule::claim(Origin::signed(42), 42, sig(&alice(), 42, &[])),
+			BadOrigin
+		);
+		assert_ok!(ClaimsModule::claim(Origin::none(), 42, sig(&alice(), 42, &[])));
+		assert_eq!(Balances::free_balance(42), 100);
+		assert_eq!(ClaimsModule::claims(eth(&alice())), None);
+		assert_eq!(ClaimsModule::total(), 200);
+
+		assert_noop!(
+			ClaimsModule::claim(Origin::none(), 42, sig(&alice(), 42, &[])),
+			Error::<Test>::InvalidEthereumSignature
+		);
+	});
+}
+
+#[test]
+fn signature_must_cover_destination() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ClaimsModule::claim(Origin::none(), 43, sig(&alice(), 42, &[])),
+			Error::<Test>::InvalidEthereumSignature
+		);
+		assert_noop!(
+			ClaimsModule::claim(Origin::none(), 42, EcdsaSignature([0; 65])),
+			Error::<Test>::InvalidEthereumSignature
+		);
+	});
+}
+
+#[test]
+fn statement_is_enforced() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			ClaimsModule::claim(Origin::none(), 42, sig(&bob(), 42, &[])),
+			Error::<Test>::InvalidEthereumSignature
+		);
+		assert_ok!(ClaimsModule::claim(Origin::none(), 42, sig(&bob(), 42, Statement::get())));
+		assert_eq!(Balances::free_balance(42), 200);
+		assert!(!ClaimsModule::statement_required(eth(&bob())));
+	});
+}
+
+#[test]
+fn mint_claim_requires_origin() {
+	new_test_ext().execute_with(|| {
+		let carol = libsecp256k1::SecretKey::parse(&[7; 32]).unwrap();
+		assert_noop!(
+			ClaimsModule::mint_claim(Origin::signed(1), eth(&carol), 50, false),
+			BadOrigin
+		);
+		assert_noop!(
+			ClaimsModule::mint_claim(Origin::root(), eth(&alice()), 50, false),
+			Error::<Test>::AlreadyHasClaim
+		);
+		assert_noop!(
+			ClaimsModule::mint_claim(Origin::root(), eth(&carol), 0, false),
+			Error::<Test>::ClaimBelowMinimum
+		);
+		assert_ok!(ClaimsModule::mint_claim(Origin::root(), eth(&carol), 50, false));
+		assert_eq!(ClaimsModule::total(), 350);
+		assert_ok!(ClaimsModule::claim(Origin::none(), 7, sig(&carol, 7, &[])));
+		assert_eq!(Balances::free_balance(7), 50);
+	});
+}
+
+#[test]
+fn validate_unsigned_checks_signature() {
+	new_test_ext().execute_with(|| {
+		let valid =
+			ClaimsCall::<Test>::claim { dest: 42, ethereum_signature: sig(&alice(), 42, &[]) };
+		assert!(ClaimsModule::validate_unsigned(TransactionSource::External, &valid).is_ok());
+
+		let invalid =
+			ClaimsCall::<Test>::claim { dest: 42, ethereum_signature: sig(&alice(), 43, &[]) };
+		assert_eq!(
+			ClaimsModule::validate_unsigned(TransactionSource::External, &invalid),
+			Err(InvalidTransaction::BadProof.into())
+		);
+		let mint = ClaimsCall::<Test>::mint_claim {
+			who: eth(&alice()),
+			value: 1,
+			statement_required: false,
+		};
+		assert_eq!(
+			ClaimsModule::validate_unsigned(TransactionSource::External, &mint),
+			Err(InvalidTransaction::Call.into())
+		);
+	});
+}
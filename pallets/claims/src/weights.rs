@@ -0,0 +1,61 @@
+//! Placeholder weights for pallet_claims
+//!
+//! NOT GENERATED: these are hand-written estimates in the format of the benchmark CLI, not
+//! measurements. Replace this file with the output of `node-template benchmark-all` on reference
+//! hardware before a release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_claims.
+pub trait WeightInfo {
+	fn claim() -> Weight;
+	fn mint_claim() -> Weight;
+}
+
+/// Placeholder weights for pallet_claims, priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Claims Claims (r:1 w:1)
+	// Storage: Claims StatementRequired (r:1 w:1)
+	// Storage: Claims Total (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn claim() -> Weight {
+		(145_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Claims Claims (r:1 w:1)
+	// Storage: Claims Total (r:1 w:1)
+	// Storage: Claims StatementRequired (r:0 w:1)
+	fn mint_claim() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Claims Claims (r:1 w:1)
+	// Storage: Claims StatementRequired (r:1 w:1)
+	// Storage: Claims Total (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn claim() -> Weight {
+		(145_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Claims Claims (r:1 w:1)
+	// Storage: Claims Total (r:1 w:1)
+	// Storage: Claims StatementRequired (r:0 w:1)
+	fn mint_claim() -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+}
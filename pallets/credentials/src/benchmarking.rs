@@ -0,0 +1,94 @@
+//! Benchmarking setup for pallet-credentials
+
+use super::*;
+
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::{traits::EnsureOrigin, BoundedVec};
+use frame_system::RawOrigin;
+
+/// Approve the whitelisted caller as an issuer.
+fn setup_issuer<T: Config>() -> T::AccountId {
+	let issuer: T::AccountId = whitelisted_caller();
+	Issuers::<T>::insert(&issuer, BoundedVec::default());
+	issuer
+}
+
+/// Approve an issuer owning a schema and a status list `0` of the largest possible size.
+fn setup_status_list<T: Config>() -> Result<T::AccountId, &'static str> {
+	let issuer = setup_issuer::<T>();
+	let schema = T::Hash::default();
+	Pallet::<T>::register_schema(RawOrigin::Signed(issuer.clone()).into(), schema)?;
+	Pallet::<T>::create_status_list(
+		RawOrigin::Signed(issuer.clone()).into(),
+		0,
+		schema,
+		T::MaxStatusListBytes::get() * 8,
+	)?;
+	Ok(issuer)
+}
+
+benchmarks! {
+	add_issuer {
+		let m in 0 .. T::MaxIssuerMetadataLen::get();
+		let origin = T::RegistrarOrigin::successful_origin();
+		let issuer: T::AccountId = whitelisted_caller();
+	}: _<T::Origin>(origin, issuer.clone(), sp_std::vec![0; m as usize])
+	verify {
+		assert!(Issuers::<T>::contains_key(&issuer));
+	}
+
+	remove_issuer {
+		let origin = T::RegistrarOrigin::successful_origin();
+		let issuer = setup_issuer::<T>();
+	}: _<T::Origin>(origin, issuer.clone())
+	verify {
+		assert!(!Issuers::<T>::contains_key(&issuer));
+	}
+
+	register_schema {
+		let issuer = setup_issuer::<T>();
+		let schema = T::Hash::default();
+	}: _(RawOrigin::Signed(issuer.clone()), schema)
+	verify {
+		assert_eq!(Schemas::<T>::get(&schema), Some(issuer));
+	}
+
+	create_status_list {
+		let b in 1 .. T::MaxStatusListBytes::get();
+		let issuer = setup_issuer::<T>();
+		let schema = T::Hash::default();
+		Pallet::<T>::register_schema(RawOrigin::Signed(issuer.clone()).into(), schema)?;
+	}: _(RawOrigin::Signed(issuer.clone()), 0, schema, b * 8)
+	verify {
+		assert!(StatusLists::<T>::contains_key(&issuer, 0));
+	}
+
+	issue_credential {
+		let issuer = setup_status_list::<T>()?;
+		let credential = T::Hash::default();
+	}: _(RawOrigin::Signed(issuer), credential, 0)
+	verify {
+		assert_eq!(Pallet::<T>::is_revoked(&credential), Some(false));
+	}
+
+	revoke_credential {
+		let issuer = setup_status_list::<T>()?;
+		let credential = T::Hash::default();
+		Pallet::<T>::issue_credential(RawOrigin::Signed(issuer.clone()).into(), credential, 0)?;
+	}: _(RawOrigin::Signed(issuer), credential)
+	verify {
+		assert_eq!(Pallet::<T>::is_revoked(&credential), Some(true));
+	}
+
+	force_revoke {
+		let origin = T::RegistrarOrigin::successful_origin();
+		let issuer = setup_status_list::<T>()?;
+		let credential = T::Hash::default();
+		Pallet::<T>::issue_credential(RawOrigin::Signed(issuer).into(), credential, 0)?;
+	}: _<T::Origin>(origin, credential)
+	verify {
+		assert_eq!(Pallet::<T>::is_revoked(&credential), Some(true));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
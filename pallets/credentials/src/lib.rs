@@ -0,0 +1,344 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// A registry for verifiable credentials.
+///
+/// Approved issuers register the schemas they issue against, allocate status lists and issue
+/// credentials into them. Every credential owns a single bit in its status list, so the chain
+/// can serve the revocation status of many credentials from a compact bitset, in the spirit of
+/// the W3C Status List 2021 specification.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::WeightInfo;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_std::prelude::*;
+
+	/// A bitset recording the revocation status of the credentials issued into it.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct StatusList<Hash, Bits> {
+		/// The schema every credential in this list conforms to.
+		pub schema: Hash,
+		/// The number of credentials this list can hold.
+		pub capacity: u32,
+		/// The index that will be handed to the next issued credential.
+		pub next_index: u32,
+		/// One bit per credential, set when the credential is revoked.
+		pub bits: Bits,
+	}
+
+	/// A credential known to the registry.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct Credential<AccountId, Hash, BlockNumber> {
+		/// The account that issued the credential.
+		pub issuer: AccountId,
+		/// The schema the credential conforms to.
+		pub schema: Hash,
+		/// The status list of the issuer holding the credential's status bit.
+		pub status_list: u32,
+		/// The position of the credential's status bit in its status list.
+		pub status_index: u32,
+		/// The block in which the credential was issued.
+		pub issued_at: BlockNumber,
+	}
+
+	pub type StatusListOf<T> = StatusList<
+		<T as frame_system::Config>::Hash,
+		BoundedVec<u8, <T as Config>::MaxStatusListBytes>,
+	>;
+
+	pub type CredentialOf<T> = Credential<
+		<T as frame_system::Config>::AccountId,
+		<T as frame_system::Config>::Hash,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin allowed to add and remove issuers and to revoke any credential.
+		type RegistrarOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum length of the metadata (e.g. a DID or a URL) attached to an issuer.
+		#[pallet::constant]
+		type MaxIssuerMetadataLen: Get<u32>;
+
+		/// The maximum size of a single status list, in bytes.
+		#[pallet::constant]
+		type MaxStatusListBytes: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The accounts allowed to issue credentials, along with their metadata.
+	#[pallet::storage]
+	#[pallet::getter(fn issuers)]
+	pub type Issuers<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, BoundedVec<u8, T::MaxIssuerMetadataLen>>;
+
+	/// The registered credential schemas and the issuer that registered them.
+	#[pallet::storage]
+	#[pallet::getter(fn schemas)]
+	pub type Schemas<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, T::AccountId>;
+
+	/// The status lists of each issuer.
+	#[pallet::storage]
+	#[pallet::getter(fn status_lists)]
+	pub type StatusLists<T: Config> =
+		StorageDoubleMap<_, Blake2_128Concat, T::AccountId, Twox64Concat, u32, StatusListOf<T>>;
+
+	/// The issued credentials, keyed by credential hash.
+	#[pallet::storage]
+	#[pallet::getter(fn credentials)]
+	pub type Credentials<T: Config> = StorageMap<_, Blake2_128Concat, T::Hash, CredentialOf<T>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An account was approved as a credential issuer. [issuer]
+		IssuerAdded(T::AccountId),
+		/// An account is no longer allowed to issue credentials. [issuer]
+		IssuerRemoved(T::AccountId),
+		/// An issuer registered a new credential schema. [issuer, schema]
+		SchemaRegistered(T::AccountId, T::Hash),
+		/// An issuer created a new status list. [issuer, list_id, capacity]
+		StatusListCreated(T::AccountId, u32, u32),
+		/// A credential was issued. [issuer, credential, list_id, index]
+		CredentialIssued(T::AccountId, T::Hash, u32, u32),
+		/// A credential was revoked by its issuer. [issuer, credential]
+		CredentialRevoked(T::AccountId, T::Hash),
+		/// A credential was revoked by `RegistrarOrigin`. [issuer, credential]
+		CredentialForceRevoked(T::AccountId, T::Hash),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is already an issuer.
+		AlreadyIssuer,
+		/// The account is not a registered issuer.
+		NotIssuer,
+		/// The issuer metadata is longer than `MaxIssuerMetadataLen`.
+		MetadataTooLong,
+		/// The schema has already been registered.
+		SchemaAlreadyRegistered,
+		/// The schema has not been registered.
+		UnknownSchema,
+		/// The schema was registered by a different issuer.
+		NotSchemaOwner,
+		/// The issuer already has a status list with this id.
+		StatusListExists,
+		/// The issuer has no status list with this id.
+		UnknownStatusList,
+		/// The requested capacity does not fit in `MaxStatusListBytes`.
+		StatusListTooLarge,
+		/// Every index of the status list has already been allocated.
+		StatusListFull,
+		/// A credential with this hash has already been issued.
+		CredentialExists,
+		/// No credential with this hash has been issued.
+		UnknownCredential,
+		/// Only the issuer of a credential may change its status.
+		NotCredentialIssuer,
+		/// The credential has already been revoked.
+		AlreadyRevoked,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Approve `issuer` to register schemas and issue credentials.
+		///
+		/// The dispatch origin must be `RegistrarOrigin`.
+		#[pallet::weight(T::WeightInfo::add_issuer(metadata.len() as u32))]
+		pub fn add_issuer(
+			origin: OriginFor<T>,
+			issuer: T::AccountId,
+			metadata: Vec<u8>,
+		) -> DispatchResult {
+			T::RegistrarOrigin::ensure_origin(origin)?;
+
+			ensure!(!Issuers::<T>::contains_key(&issuer), Error::<T>::AlreadyIssuer);
+			let metadata: BoundedVec<_, _> =
+				metadata.try_into().map_err(|_| Error::<T>::MetadataTooLong)?;
+
+			Issuers::<T>::insert(&issuer, metadata);
+
+			Self::deposit_event(Event::IssuerAdded(issuer));
+			Ok(())
+		}
+
+		/// Revoke the issuing rights of `issuer`.
+		///
+		/// Credentials that were already issued stay in the registry and can still be revoked by
+		/// their issuer. The dispatch origin must be `RegistrarOrigin`.
+		#[pallet::weight(T::WeightInfo::remove_issuer())]
+		pub fn remove_issuer(origin: OriginFor<T>, issuer: T::AccountId) -> DispatchResult {
+			T::RegistrarOrigin::ensure_origin(origin)?;
+
+			ensure!(Issuers::<T>::contains_key(&issuer), Error::<T>::NotIssuer);
+			Issuers::<T>::remove(&issuer);
+
+			Self::deposit_event(Event::IssuerRemoved(issuer));
+			Ok(())
+		}
+
+		/// Register the hash of a credential schema under the calling issuer.
+		#[pallet::weight(T::WeightInfo::register_schema())]
+		pub fn register_schema(origin: OriginFor<T>, schema: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Issuers::<T>::contains_key(&who), Error::<T>::NotIssuer);
+			ensure!(!Schemas::<T>::contains_key(&schema), Error::<T>::SchemaAlreadyRegistered);
+
+			Schemas::<T>::insert(&schema, &who);
+
+			Self::deposit_event(Event::SchemaRegistered(who, schema));
+			Ok(())
+		}
+
+		/// Create a status list able to track `capacity` credentials of `schema`.
+		#[pallet::weight(T::WeightInfo::create_status_list(
+			(capacity.saturating_add(7) / 8).min(T::MaxStatusListBytes::get())
+		))]
+		pub fn create_status_list(
+			origin: OriginFor<T>,
+			list_id: u32,
+			schema: T::Hash,
+			capacity: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Issuers::<T>::contains_key(&who), Error::<T>::NotIssuer);
+			let owner = Schemas::<T>::get(&schema).ok_or(Error::<T>::UnknownSchema)?;
+			ensure!(owner == who, Error::<T>::NotSchemaOwner);
+			ensure!(!StatusLists::<T>::contains_key(&who, list_id), Error::<T>::StatusListExists);
+
+			let len = capacity.checked_add(7).ok_or(Error::<T>::StatusListTooLarge)? / 8;
+			ensure!(len <= T::MaxStatusListBytes::get(), Error::<T>::StatusListTooLarge);
+			let bits: BoundedVec<_, _> = sp_std::vec![0u8; len as usize]
+				.try_into()
+				.map_err(|_| Error::<T>::StatusListTooLarge)?;
+
+			StatusLists::<T>::insert(
+				&who,
+				list_id,
+				StatusList { schema, capacity, next_index: 0, bits },
+			);
+
+			Self::deposit_event(Event::StatusListCreated(who, list_id, capacity));
+			Ok(())
+		}
+
+		/// Issue `credential` and allocate it the next free bit of the status list `list_id`.
+		#[pallet::weight(T::WeightInfo::issue_credential())]
+		pub fn issue_credential(
+			origin: OriginFor<T>,
+			credential: T::Hash,
+			list_id: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(Issuers::<T>::contains_key(&who), Error::<T>::NotIssuer);
+			ensure!(!Credentials::<T>::contains_key(&credential), Error::<T>::CredentialExists);
+
+			let (index, schema) = StatusLists::<T>::try_mutate(&who, list_id, |maybe_list| {
+				let list = maybe_list.as_mut().ok_or(Error::<T>::UnknownStatusList)?;
+				ensure!(list.next_index < list.capacity, Error::<T>::StatusListFull);
+				let index = list.next_index;
+				list.next_index += 1;
+				Ok::<_, Error<T>>((index, list.schema))
+			})?;
+
+			Credentials::<T>::insert(
+				&credential,
+				Credential {
+					issuer: who.clone(),
+					schema,
+					status_list: list_id,
+					status_index: index,
+					issued_at: <frame_system::Pallet<T>>::block_number(),
+				},
+			);
+
+			Self::deposit_event(Event::CredentialIssued(who, credential, list_id, index));
+			Ok(())
+		}
+
+		/// Revoke `credential` by setting its bit in the status list.
+		///
+		/// The dispatch origin must be the issuer of the credential.
+		#[pallet::weight(T::WeightInfo::revoke_credential())]
+		pub fn revoke_credential(origin: OriginFor<T>, credential: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let info = Credentials::<T>::get(&credential).ok_or(Error::<T>::UnknownCredential)?;
+			ensure!(info.issuer == who, Error::<T>::NotCredentialIssuer);
+			Self::set_revoked(&info)?;
+
+			Self::deposit_event(Event::CredentialRevoked(who, credential));
+			Ok(())
+		}
+
+		/// Revoke `credential` on behalf of its issuer, for example after a dispute or when the
+		/// issuer has lost its key.
+		///
+		/// The dispatch origin must be `RegistrarOrigin`.
+		#[pallet::weight(T::WeightInfo::force_revoke())]
+		pub fn force_revoke(origin: OriginFor<T>, credential: T::Hash) -> DispatchResult {
+			T::RegistrarOrigin::ensure_origin(origin)?;
+
+			let info = Credentials::<T>::get(&credential).ok_or(Error::<T>::UnknownCredential)?;
+			Self::set_revoked(&info)?;
+
+			Self::deposit_event(Event::CredentialForceRevoked(info.issuer, credential));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether `credential` has been revoked, or `None` if it was never issued.
+		pub fn is_revoked(credential: &T::Hash) -> Option<bool> {
+			let info = Credentials::<T>::get(credential)?;
+			let list = StatusLists::<T>::get(&info.issuer, info.status_list)?;
+			let (byte, mask) = Self::bit_position(info.status_index);
+			list.bits.get(byte).map(|slot| slot & mask != 0)
+		}
+
+		/// Set the status bit of the credential described by `info`.
+		fn set_revoked(info: &CredentialOf<T>) -> DispatchResult {
+			StatusLists::<T>::try_mutate(&info.issuer, info.status_list, |maybe_list| {
+				let list = maybe_list.as_mut().ok_or(Error::<T>::UnknownStatusList)?;
+				let (byte, mask) = Self::bit_position(info.status_index);
+				let slot = list.bits.get_mut(byte).ok_or(Error::<T>::UnknownStatusList)?;
+				ensure!(*slot & mask == 0, Error::<T>::AlreadyRevoked);
+				*slot |= mask;
+				Ok(())
+			})
+		}
+
+		/// The byte offset and bit mask of `index` within a status list.
+		fn bit_position(index: u32) -> (usize, u8) {
+			((index / 8) as usize, 1u8 << (index % 8))
+		}
+	}
+}
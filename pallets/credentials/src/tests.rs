@@ -0,0 +1,134 @@
+use crate::{mock::*, Error, Event as CredentialsEvent};
+use frame_support::{assert_noop, assert_ok};
+use sp_core::H256;
+use sp_runtime::traits::BadOrigin;
+
+const ISSUER: u64 = 1;
+const SCHEMA: H256 = H256::repeat_byte(1);
+
+fn setup_issuer() {
+	assert_ok!(Credentials::add_issuer(Origin::root(), ISSUER, b"did:example:1".to_vec()));
+	assert_ok!(Credentials::register_schema(Origin::signed(ISSUER), SCHEMA));
+	assert_ok!(Credentials::create_status_list(Origin::signed(ISSUER), 0, SCHEMA, 16));
+}
+
+#[test]
+fn only_registrar_can_add_issuers() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Credentials::add_issuer(Origin::signed(2), 2, vec![]), BadOrigin);
+		assert_noop!(
+			Credentials::add_issuer(Origin::root(), 2, vec![0; 65]),
+			Error::<Test>::MetadataTooLong
+		);
+		assert_ok!(Credentials::add_issuer(Origin::root(), 2, vec![]));
+		assert_noop!(
+			Credentials::add_issuer(Origin::root(), 2, vec![]),
+			Error::<Test>::AlreadyIssuer
+		);
+	});
+}
+
+#[test]
+fn status_lists_are_bounded_by_schema_owner_and_size() {
+	new_test_ext().execute_with(|| {
+		setup_issuer();
+		assert_ok!(Credentials::add_issuer(Origin::root(), 2, vec![]));
+		assert_noop!(
+			Credentials::create_status_list(Origin::signed(2), 0, SCHEMA, 8),
+			Error::<Test>::NotSchemaOwner
+		);
+		assert_noop!(
+			Credentials::create_status_list(Origin::signed(ISSUER), 0, SCHEMA, 8),
+			Error::<Test>::StatusListExists
+		);
+		// `MaxStatusListBytes` is 4 in the mock, so at most 32 credentials fit in one list.
+		assert_noop!(
+			Credentials::create_status_list(Origin::signed(ISSUER), 1, SCHEMA, 33),
+			Error::<Test>::StatusListTooLarge
+		);
+		assert_ok!(Credentials::create_status_list(Origin::signed(ISSUER), 1, SCHEMA, 32));
+	});
+}
+
+#[test]
+fn issue_and_revoke_credential() {
+	new_test_ext().execute_with(|| {
+		setup_issuer();
+		let first = H256::repeat_byte(10);
+		let second = H256::repeat_byte(11);
+
+		assert_ok!(Credentials::issue_credential(Origin::signed(ISSUER), first, 0));
+		assert_ok!(Credentials::issue_credential(Origin::signed(ISSUER), second, 0));
+		assert_eq!(Credentials::credentials(second).unwrap().status_index, 1);
+		assert_eq!(Credentials::is_revoked(&first), Some(false));
+
+		assert_noop!(
+			Credentials::revoke_credential(Origin::signed(2), first),
+			Error::<Test>::NotCredentialIssuer
+		);
+		assert_ok!(Credentials::revoke_credential(Origin::signed(ISSUER), first));
+		assert_eq!(Credentials::is_revoked(&first), Some(true));
+		assert_eq!(Credentials::is_revoked(&second), Some(false));
+		assert_noop!(
+			Credentials::revoke_credential(Origin::signed(ISSUER), first),
+			Error::<Test>::AlreadyRevoked
+		);
+	});
+}
+
+#[test]
+fn status_list_capacity_is_enforced() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Credentials::add_issuer(Origin::root(), ISSUER, vec![]));
+		assert_ok!(Credentials::register_schema(Origin::signed(ISSUER), SCHEMA));
+		assert_ok!(Credentials::create_status_list(Origin::signed(ISSUER), 0, SCHEMA, 1));
+
+		assert_ok!(Credentials::issue_credential(Origin::signed(ISSUER), H256::repeat_byte(1), 0));
+		assert_noop!(
+			Credentials::issue_credential(Origin::signed(ISSUER), H256::repeat_byte(2), 0),
+			Error::<Test>::StatusListFull
+		);
+	});
+}
+
+#[test]
+fn registrar_can_force_revoke() {
+	new_test_ext().execute_with(|| {
+		// Events are not recorded in the genesis block.
+		System::set_block_number(1);
+		setup_issuer();
+		let credential = H256::repeat_byte(10);
+		assert_ok!(Credentials::issue_credential(Origin::signed(ISSUER), credential, 0));
+
+		assert_noop!(Credentials::force_revoke(Origin::signed(ISSUER), credential), BadOrigin);
+		assert_noop!(
+			Credentials::force_revoke(Origin::root(), H256::repeat_byte(11)),
+			Error::<Test>::UnknownCredential
+		);
+		assert_ok!(Credentials::force_revoke(Origin::root(), credential));
+		assert_eq!(Credentials::is_revoked(&credential), Some(true));
+		System::assert_last_event(
+			CredentialsEvent::CredentialForceRevoked(ISSUER, credential).into(),
+		);
+		assert_noop!(
+			Credentials::force_revoke(Origin::root(), credential),
+			Error::<Test>::AlreadyRevoked
+		);
+	});
+}
+
+#[test]
+fn removed_issuer_can_still_revoke() {
+	new_test_ext().execute_with(|| {
+		setup_issuer();
+		let credential = H256::repeat_byte(10);
+		assert_ok!(Credentials::issue_credential(Origin::signed(ISSUER), credential, 0));
+
+		assert_ok!(Credentials::remove_issuer(Origin::root(), ISSUER));
+		assert_noop!(
+			Credentials::issue_credential(Origin::signed(ISSUER), H256::repeat_byte(11), 0),
+			Error::<Test>::NotIssuer
+		);
+		assert_ok!(Credentials::revoke_credential(Origin::signed(ISSUER), credential));
+	});
+}
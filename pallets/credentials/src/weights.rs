@@ -0,0 +1,134 @@
+//! Placeholder weights for pallet_credentials
+//!
+//! NOT GENERATED: these are hand-written estimates in the format of the benchmark CLI, not
+//! measurements. Replace this file with the output of `node-template benchmark-all` on reference
+//! hardware before a release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_credentials.
+pub trait WeightInfo {
+	fn add_issuer(m: u32, ) -> Weight;
+	fn remove_issuer() -> Weight;
+	fn register_schema() -> Weight;
+	fn create_status_list(b: u32, ) -> Weight;
+	fn issue_credential() -> Weight;
+	fn revoke_credential() -> Weight;
+	fn force_revoke() -> Weight;
+}
+
+/// Placeholder weights for pallet_credentials, priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Credentials Issuers (r:1 w:1)
+	fn add_issuer(m: u32, ) -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Credentials Issuers (r:1 w:1)
+	fn remove_issuer() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Credentials Issuers (r:1 w:0)
+	// Storage: Credentials Schemas (r:1 w:1)
+	fn register_schema() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Credentials Issuers (r:1 w:0)
+	// Storage: Credentials Schemas (r:1 w:0)
+	// Storage: Credentials StatusLists (r:1 w:1)
+	fn create_status_list(b: u32, ) -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Credentials Issuers (r:1 w:0)
+	// Storage: Credentials Credentials (r:1 w:1)
+	// Storage: Credentials StatusLists (r:1 w:1)
+	fn issue_credential() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Credentials Credentials (r:1 w:0)
+	// Storage: Credentials StatusLists (r:1 w:1)
+	fn revoke_credential() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Credentials Credentials (r:1 w:0)
+	// Storage: Credentials StatusLists (r:1 w:1)
+	fn force_revoke() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Credentials Issuers (r:1 w:1)
+	fn add_issuer(m: u32, ) -> Weight {
+		(21_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Credentials Issuers (r:1 w:1)
+	fn remove_issuer() -> Weight {
+		(20_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Credentials Issuers (r:1 w:0)
+	// Storage: Credentials Schemas (r:1 w:1)
+	fn register_schema() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Credentials Issuers (r:1 w:0)
+	// Storage: Credentials Schemas (r:1 w:0)
+	// Storage: Credentials StatusLists (r:1 w:1)
+	fn create_status_list(b: u32, ) -> Weight {
+		(28_000_000 as Weight)
+			.saturating_add((3_000 as Weight).saturating_mul(b as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Credentials Issuers (r:1 w:0)
+	// Storage: Credentials Credentials (r:1 w:1)
+	// Storage: Credentials StatusLists (r:1 w:1)
+	fn issue_credential() -> Weight {
+		(36_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Credentials Credentials (r:1 w:0)
+	// Storage: Credentials StatusLists (r:1 w:1)
+	fn revoke_credential() -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Credentials Credentials (r:1 w:0)
+	// Storage: Credentials StatusLists (r:1 w:1)
+	fn force_revoke() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
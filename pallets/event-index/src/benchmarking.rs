@@ -0,0 +1,39 @@
+//! Benchmarking setup for pallet-event-index
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_support::{
+	traits::{Get, Hooks},
+	weights::Weight,
+};
+use sp_runtime::traits::{One, Zero};
+
+benchmarks! {
+	prune_bloom {
+		let retention = T::BloomRetention::get();
+		Blooms::<T>::insert(T::BlockNumber::zero(), Bloom::default());
+	}: {
+		Pallet::<T>::on_initialize(retention);
+	}
+	verify {
+		assert!(retention.is_zero() || !Blooms::<T>::contains_key(T::BlockNumber::zero()));
+	}
+
+	index_events {
+		let e in 0 .. T::MaxIndexedEvents::get();
+		// No events are deposited in the genesis block.
+		let n = T::BlockNumber::one();
+		frame_system::Pallet::<T>::set_block_number(n);
+		for _ in 0 .. e {
+			frame_system::Pallet::<T>::deposit_event(frame_system::Event::<T>::CodeUpdated);
+		}
+	}: {
+		Pallet::<T>::on_idle(n, Weight::max_value());
+	}
+	verify {
+		assert!(Blooms::<T>::contains_key(n));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
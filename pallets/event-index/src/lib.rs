@@ -0,0 +1,189 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Per-block bloom filters over the deposited events.
+///
+/// In its `on_idle` hook, the pallet adds a [`Topic`] for every event deposited so far and for
+/// every account the runtime's [`EventAccounts`] reports for it to a 2048-bit [`Bloom`]
+/// filter. Light clients and RPC services read the filter of a block to skip blocks that
+/// certainly contain no events for an account, without downloading their events. Filters are
+/// kept for `BloomRetention` blocks.
+///
+/// The weight of indexing grows with the number of events, so blocks with more than
+/// `MaxIndexedEvents` events, or without enough weight left to index them, get no filter and
+/// clients have to read their events. Events deposited after this pallet's `on_idle` hook,
+/// such as by `on_finalize` hooks, are not indexed.
+pub use pallet::*;
+
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+use sp_std::prelude::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// The number of bits set for every topic added to a bloom filter.
+const BITS_PER_TOPIC: usize = 3;
+
+/// A 2048-bit bloom filter.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Bloom(pub [u8; 256]);
+
+impl Default for Bloom {
+	fn default() -> Self {
+		Self([0; 256])
+	}
+}
+
+impl Bloom {
+	/// The bits representing `item`.
+	fn bits(item: &[u8]) -> [usize; BITS_PER_TOPIC] {
+		let hash = sp_io::hashing::blake2_256(item);
+		let mut bits = [0; BITS_PER_TOPIC];
+		for (i, bit) in bits.iter_mut().enumerate() {
+			*bit = (u16::from_be_bytes([hash[2 * i], hash[2 * i + 1]]) & 2047) as usize;
+		}
+		bits
+	}
+
+	/// Add `topic` to the filter.
+	pub fn accrue<AccountId: Encode>(&mut self, topic: &Topic<AccountId>) {
+		for bit in Self::bits(&topic.encode()) {
+			self.0[bit / 8] |= 1 << (bit % 8);
+		}
+	}
+
+	/// Whether `topic` may have been added to the filter. `false` is always accurate.
+	pub fn may_contain<AccountId: Encode>(&self, topic: &Topic<AccountId>) -> bool {
+		Self::bits(&topic.encode())
+			.iter()
+			.all(|bit| self.0[bit / 8] & (1 << (bit % 8)) != 0)
+	}
+}
+
+/// An item added to the bloom filter of a block.
+///
+/// Events are identified by the index of their pallet in the runtime and their index within the
+/// pallet's event enum, which are the first two bytes of their encoding.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum Topic<AccountId> {
+	/// An event of the given pallet and event index. [pallet, event]
+	Event(u8, u8),
+	/// Any event concerning the account.
+	Account(AccountId),
+	/// An event of the given pallet and event index concerning the account.
+	/// [pallet, event, account]
+	AccountEvent(u8, u8, AccountId),
+}
+
+/// Extract the accounts an event concerns.
+pub trait EventAccounts<Event, AccountId> {
+	/// The accounts `event` concerns, such as the owner of a claim.
+	fn accounts(event: &Event) -> Vec<AccountId>;
+}
+
+impl<Event, AccountId> EventAccounts<Event, AccountId> for () {
+	fn accounts(_: &Event) -> Vec<AccountId> {
+		Vec::new()
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::{Bloom, EventAccounts, Topic, WeightInfo};
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, Zero};
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The accounts the runtime's events concern.
+		type EventAccounts: EventAccounts<<Self as frame_system::Config>::Event, Self::AccountId>;
+
+		/// The number of blocks bloom filters are kept for. Zero keeps them forever.
+		#[pallet::constant]
+		type BloomRetention: Get<Self::BlockNumber>;
+
+		/// The maximum number of events of a block that are indexed. Blocks with more events
+		/// get no filter.
+		#[pallet::constant]
+		type MaxIndexedEvents: Get<u32>;
+
+		/// Weight information for the hooks of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The bloom filter of the events of every recent block.
+	#[pallet::storage]
+	#[pallet::getter(fn blooms)]
+	pub type Blooms<T: Config> = StorageMap<_, Twox64Concat, T::BlockNumber, Bloom>;
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_initialize(n: T::BlockNumber) -> Weight {
+			let retention = T::BloomRetention::get();
+			if !retention.is_zero() && n >= retention {
+				Blooms::<T>::remove(n.saturating_sub(retention));
+			}
+			T::WeightInfo::prune_bloom()
+		}
+
+		fn on_idle(n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			// Reading the event count.
+			let skipped = T::DbWeight::get().reads(1);
+			let count = frame_system::Pallet::<T>::event_count();
+			if count > T::MaxIndexedEvents::get() {
+				return skipped
+			}
+			let weight = Self::index_weight(count);
+			if weight > remaining_weight {
+				return skipped
+			}
+
+			let mut bloom = Bloom::default();
+			for record in frame_system::Pallet::<T>::events() {
+				let (pallet, event) =
+					match record.event.using_encoded(|e| e.get(..2).map(|i| (i[0], i[1]))) {
+						Some(index) => index,
+						None => continue,
+					};
+				bloom.accrue::<T::AccountId>(&Topic::Event(pallet, event));
+				for account in T::EventAccounts::accounts(&record.event) {
+					bloom.accrue(&Topic::AccountEvent(pallet, event, account.clone()));
+					bloom.accrue(&Topic::Account(account));
+				}
+			}
+			Blooms::<T>::insert(n, bloom);
+
+			weight
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The weight of indexing a block with `events` events: reading the event count and the
+		/// events, hashing their topics and writing the filter.
+		pub fn index_weight(events: u32) -> Weight {
+			T::WeightInfo::index_events(events)
+		}
+
+		/// Whether block `n` may contain an event matching `topic`, or `None` if the block has no
+		/// filter, for example because it was pruned.
+		pub fn may_contain(n: T::BlockNumber, topic: &Topic<T::AccountId>) -> Option<bool> {
+			Blooms::<T>::get(n).map(|bloom| bloom.may_contain(topic))
+		}
+	}
+}
@@ -0,0 +1,89 @@
+use crate as pallet_event_index;
+use frame_support::parameter_types;
+use frame_system as system;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+// Configure a mock runtime to test the pallet.
+frame_support::construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		TemplateModule: pallet_template::{Pallet, Call, Storage, Event<T>},
+		EventIndex: pallet_event_index::{Pallet, Storage},
+	}
+);
+
+parameter_types! {
+	pub const BlockHashCount: u64 = 250;
+	pub const SS58Prefix: u8 = 42;
+}
+
+impl system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type DbWeight = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = BlockHashCount;
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = SS58Prefix;
+	type OnSetCode = ();
+}
+
+impl pallet_template::Config for Test {
+	type Event = Event;
+	type WeightInfo = ();
+}
+
+/// Reports the account that stored a value in the template pallet.
+pub struct TemplateAccounts;
+
+impl pallet_event_index::EventAccounts<Event, u64> for TemplateAccounts {
+	fn accounts(event: &Event) -> Vec<u64> {
+		match event {
+			Event::TemplateModule(pallet_template::Event::SomethingStored(_, who)) => vec![*who],
+			_ => vec![],
+		}
+	}
+}
+
+parameter_types! {
+	pub const BloomRetention: u64 = 3;
+	pub const MaxIndexedEvents: u32 = 2;
+}
+
+impl pallet_event_index::Config for Test {
+	type EventAccounts = TemplateAccounts;
+	type BloomRetention = BloomRetention;
+	type MaxIndexedEvents = MaxIndexedEvents;
+	type WeightInfo = ();
+}
+
+// Build genesis storage according to the mock runtime.
+pub fn new_test_ext() -> sp_io::TestExternalities {
+	system::GenesisConfig::default().build_storage::<Test>().unwrap().into()
+}
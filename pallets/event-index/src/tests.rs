@@ -0,0 +1,89 @@
+use crate::{mock::*, Topic};
+use codec::Encode;
+use frame_support::{assert_ok, traits::Hooks};
+
+/// Run the hooks of the pallet for block `n` with no weight limit, as the executive would.
+fn index(n: u64) {
+	EventIndex::on_initialize(n);
+	EventIndex::on_idle(n, u64::MAX);
+}
+
+/// Store a value in the template pallet as `who`, and return the pallet and event index of the
+/// deposited event.
+fn store_something(who: u64) -> (u8, u8) {
+	assert_ok!(TemplateModule::do_something(Origin::signed(who), 42));
+	let event = Event::TemplateModule(pallet_template::Event::SomethingStored(42, who)).encode();
+	(event[0], event[1])
+}
+
+#[test]
+fn events_and_accounts_are_indexed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let (pallet, event) = store_something(7);
+		index(1);
+
+		assert_eq!(EventIndex::may_contain(1, &Topic::Event(pallet, event)), Some(true));
+		assert_eq!(EventIndex::may_contain(1, &Topic::Account(7)), Some(true));
+		assert_eq!(EventIndex::may_contain(1, &Topic::AccountEvent(pallet, event, 7)), Some(true));
+		assert_eq!(EventIndex::may_contain(1, &Topic::Account(8)), Some(false));
+		assert_eq!(EventIndex::may_contain(2, &Topic::Account(7)), None);
+	});
+}
+
+#[test]
+fn blocks_without_matching_events_are_ruled_out() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		store_something(7);
+		index(1);
+
+		System::reset_events();
+		System::set_block_number(2);
+		store_something(8);
+		index(2);
+
+		assert_eq!(EventIndex::may_contain(1, &Topic::Account(8)), Some(false));
+		assert_eq!(EventIndex::may_contain(2, &Topic::Account(8)), Some(true));
+		assert_eq!(EventIndex::may_contain(2, &Topic::Account(7)), Some(false));
+	});
+}
+
+#[test]
+fn old_filters_are_pruned() {
+	new_test_ext().execute_with(|| {
+		for n in 1..=4 {
+			System::set_block_number(n);
+			index(n);
+		}
+		assert!(EventIndex::blooms(1).is_none());
+		assert!(EventIndex::blooms(2).is_some());
+		assert!(EventIndex::blooms(4).is_some());
+	});
+}
+
+#[test]
+fn busy_blocks_are_not_indexed() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		store_something(7);
+		store_something(7);
+		store_something(7);
+		index(1);
+		assert_eq!(EventIndex::may_contain(1, &Topic::Account(7)), None);
+
+		System::reset_events();
+		System::set_block_number(2);
+		store_something(7);
+		// Indexing is skipped without enough weight left in the block.
+		assert!(
+			EventIndex::on_idle(2, EventIndex::index_weight(1) - 1) < EventIndex::index_weight(1)
+		);
+		assert_eq!(EventIndex::may_contain(2, &Topic::Account(7)), None);
+		assert_eq!(
+			EventIndex::on_idle(2, EventIndex::index_weight(1)),
+			EventIndex::index_weight(1)
+		);
+		assert_eq!(EventIndex::may_contain(2, &Topic::Account(7)), Some(true));
+	});
+}
@@ -0,0 +1,55 @@
+//! Placeholder weights for pallet_event_index
+//!
+//! NOT GENERATED: these are hand-written estimates in the format of the benchmark CLI, not
+//! measurements. Replace this file with the output of `node-template benchmark-all` on reference
+//! hardware before a release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_event_index.
+pub trait WeightInfo {
+	fn prune_bloom() -> Weight;
+	fn index_events(e: u32, ) -> Weight;
+}
+
+/// Placeholder weights for pallet_event_index, priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: EventIndex Blooms (r:0 w:1)
+	fn prune_bloom() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System EventCount (r:1 w:0)
+	// Storage: System Events (r:1 w:0)
+	// Storage: EventIndex Blooms (r:0 w:1)
+	fn index_events(e: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add((10_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: EventIndex Blooms (r:0 w:1)
+	fn prune_bloom() -> Weight {
+		(10_000 as Weight)
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System EventCount (r:1 w:0)
+	// Storage: System Events (r:1 w:0)
+	// Storage: EventIndex Blooms (r:0 w:1)
+	fn index_events(e: u32, ) -> Weight {
+		(10_000 as Weight)
+			.saturating_add((10_000 as Weight).saturating_mul(e as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
@@ -0,0 +1,27 @@
+//! Benchmarking setup for pallet-idempotency
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::Get;
+
+benchmarks! {
+	record_key {
+		// A full buffer, so the oldest key is dropped.
+		let who: T::AccountId = account("who", 0, 0);
+		for i in 0 .. T::MaxRecentKeys::get() {
+			let mut key = IdempotencyKey::default();
+			key[..4].copy_from_slice(&i.to_le_bytes());
+			Pallet::<T>::record(&who, key);
+		}
+		let key = [u8::MAX; 16];
+	}: {
+		assert!(!Pallet::<T>::is_used(&who, &key));
+		Pallet::<T>::record(&who, key);
+	}
+	verify {
+		assert!(Pallet::<T>::is_used(&who, &key));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
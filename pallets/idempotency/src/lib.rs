@@ -0,0 +1,184 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Deduplication of retried transactions.
+///
+/// Clients attach an optional [`IdempotencyKey`] to a signed transaction through the
+/// [`CheckIdempotencyKey`] signed extension. The last `MaxRecentKeys` keys included for every
+/// account are kept, and a transaction reusing one of them is invalid. Backends that retry a
+/// submission with the same key, for example after a timeout, therefore cannot have it
+/// included twice even when the retry is signed with a new nonce.
+pub use pallet::*;
+
+use codec::{Decode, Encode};
+use frame_support::weights::DispatchInfo;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+use sp_std::vec;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// A key chosen by the client to identify a submission across retries.
+pub type IdempotencyKey = [u8; 16];
+
+/// The custom `InvalidTransaction` code of transactions reusing a recent key.
+pub const DUPLICATE_KEY: u8 = 0x1d;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::{IdempotencyKey, WeightInfo};
+	use frame_support::pallet_prelude::*;
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// The number of keys kept for every account.
+		#[pallet::constant]
+		type MaxRecentKeys: Get<u32>;
+
+		/// Weight information for the signed extension of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The most recent keys included for every account, oldest first.
+	#[pallet::storage]
+	#[pallet::getter(fn recent_keys)]
+	pub type RecentKeys<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<IdempotencyKey, T::MaxRecentKeys>,
+		ValueQuery,
+	>;
+
+	impl<T: Config> Pallet<T> {
+		/// Whether `key` was recently included for `who`.
+		pub fn is_used(who: &T::AccountId, key: &IdempotencyKey) -> bool {
+			RecentKeys::<T>::get(who).contains(key)
+		}
+
+		/// Record `key` for `who`, dropping the oldest key if the buffer is full.
+		pub fn record(who: &T::AccountId, key: IdempotencyKey) {
+			RecentKeys::<T>::mutate(who, |keys| {
+				if keys.len() as u32 >= T::MaxRecentKeys::get() && !keys.is_empty() {
+					keys.remove(0);
+				}
+				// Only fails if `MaxRecentKeys` is zero, in which case no keys are kept.
+				let _ = keys.try_push(key);
+			});
+		}
+	}
+}
+
+/// Reject signed transactions carrying a key recently included for the same account.
+///
+/// Transactions without a key are not checked. Pending transactions with the same key
+/// provide the same tag, so the transaction pool keeps only one of them. Recording the key of an
+/// included transaction is added to the weight of the block.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckIdempotencyKey<T: Config + Send + Sync>(
+	pub Option<IdempotencyKey>,
+	sp_std::marker::PhantomData<T>,
+);
+
+impl<T: Config + Send + Sync> CheckIdempotencyKey<T> {
+	/// Create a new instance of the extension attaching `key`.
+	pub fn new(key: Option<IdempotencyKey>) -> Self {
+		Self(key, Default::default())
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckIdempotencyKey<T> {
+	fn default() -> Self {
+		Self::new(None)
+	}
+}
+
+impl<T: Config + Send + Sync> From<Option<IdempotencyKey>> for CheckIdempotencyKey<T> {
+	fn from(key: Option<IdempotencyKey>) -> Self {
+		Self::new(key)
+	}
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckIdempotencyKey<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckIdempotencyKey({:?})", self.0)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckIdempotencyKey<T>
+where
+	T::Call: Dispatchable<Info = DispatchInfo>,
+{
+	const IDENTIFIER: &'static str = "CheckIdempotencyKey";
+	type AccountId = T::AccountId;
+	type Call = T::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		match self.0 {
+			None => Ok(ValidTransaction::default()),
+			Some(key) if Pallet::<T>::is_used(who, &key) =>
+				Err(InvalidTransaction::Custom(DUPLICATE_KEY).into()),
+			Some(key) => Ok(ValidTransaction {
+				provides: vec![(b"idempotency", who, key).encode()],
+				..Default::default()
+			}),
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		_call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<(), TransactionValidityError> {
+		if let Some(key) = self.0 {
+			if Pallet::<T>::is_used(who, &key) {
+				return Err(InvalidTransaction::Custom(DUPLICATE_KEY).into())
+			}
+			Pallet::<T>::record(who, key);
+			frame_system::Pallet::<T>::register_extra_weight_unchecked(
+				T::WeightInfo::record_key(),
+				info.class,
+			);
+		}
+		Ok(())
+	}
+}
@@ -0,0 +1,96 @@
+use crate::{mock::*, CheckIdempotencyKey, IdempotencyKey, WeightInfo, DUPLICATE_KEY};
+use frame_support::{
+	assert_ok,
+	weights::{DispatchClass, DispatchInfo},
+};
+use sp_runtime::{
+	traits::SignedExtension,
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+const USER: u64 = 1;
+const OTHER: u64 = 2;
+
+fn remark() -> Call {
+	Call::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn key(i: u8) -> Option<IdempotencyKey> {
+	Some([i; 16])
+}
+
+/// Run the extension for a transaction from `who` as if it was included in the current block.
+fn include(who: u64, key: Option<IdempotencyKey>) -> Result<(), TransactionValidityError> {
+	CheckIdempotencyKey::<Test>::new(key).pre_dispatch(
+		&who,
+		&remark(),
+		&DispatchInfo::default(),
+		10,
+	)
+}
+
+fn validate(who: u64, key: Option<IdempotencyKey>) -> bool {
+	CheckIdempotencyKey::<Test>::new(key)
+		.validate(&who, &remark(), &DispatchInfo::default(), 10)
+		.is_ok()
+}
+
+#[test]
+fn transactions_without_key_are_not_checked() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(include(USER, None));
+		assert_ok!(include(USER, None));
+		assert!(!crate::RecentKeys::<Test>::contains_key(USER));
+	});
+}
+
+#[test]
+fn reused_keys_are_rejected() {
+	new_test_ext().execute_with(|| {
+		assert!(validate(USER, key(1)));
+		assert_ok!(include(USER, key(1)));
+
+		assert!(!validate(USER, key(1)));
+		assert_eq!(include(USER, key(1)), Err(InvalidTransaction::Custom(DUPLICATE_KEY).into()));
+		// Keys are tracked per account.
+		assert_ok!(include(OTHER, key(1)));
+	});
+}
+
+#[test]
+fn oldest_keys_are_forgotten() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(include(USER, key(1)));
+		assert_ok!(include(USER, key(2)));
+		assert_ok!(include(USER, key(3)));
+
+		assert_eq!(Idempotency::recent_keys(USER).into_inner(), vec![[2; 16], [3; 16]]);
+		assert!(validate(USER, key(1)));
+	});
+}
+
+#[test]
+fn pending_duplicates_provide_the_same_tag() {
+	new_test_ext().execute_with(|| {
+		let provides = |who| {
+			CheckIdempotencyKey::<Test>::new(key(1))
+				.validate(&who, &remark(), &DispatchInfo::default(), 10)
+				.unwrap()
+				.provides
+		};
+		assert_eq!(provides(USER), provides(USER));
+		assert_ne!(provides(USER), provides(OTHER));
+	});
+}
+
+#[test]
+fn recording_a_key_is_weighed() {
+	new_test_ext().execute_with(|| {
+		let weight = || *System::block_weight().get(DispatchClass::Normal);
+		assert_ok!(include(USER, None));
+		assert_eq!(weight(), 0);
+
+		assert_ok!(include(USER, key(1)));
+		assert_eq!(weight(), <() as WeightInfo>::record_key());
+	});
+}
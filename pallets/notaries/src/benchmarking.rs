@@ -0,0 +1,90 @@
+//! Benchmarking setup for pallet-notaries
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{sp_runtime::traits::Bounded, traits::Currency};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// Register the whitelisted caller as a notary with the minimum stake.
+fn setup_notary<T: Config>() -> Result<T::AccountId, &'static str> {
+	let notary: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&notary, BalanceOf::<T>::max_value() / 2u32.into());
+	let profile = sp_std::vec![0; T::MaxProfileLen::get() as usize];
+	Pallet::<T>::register(
+		RawOrigin::Signed(notary.clone()).into(),
+		T::MinimumStake::get(),
+		profile,
+	)?;
+	Ok(notary)
+}
+
+/// A funded client account.
+fn setup_client<T: Config>(index: u32) -> T::AccountId {
+	let client: T::AccountId = account("client", index, SEED);
+	T::Currency::make_free_balance_be(&client, BalanceOf::<T>::max_value() / 2u32.into());
+	client
+}
+
+benchmarks! {
+	register {
+		let n in 0 .. T::MaxProfileLen::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), T::MinimumStake::get(), sp_std::vec![0; n as usize])
+	verify {
+		assert!(Notaries::<T>::contains_key(&caller));
+	}
+
+	bond_extra {
+		let notary = setup_notary::<T>()?;
+	}: _(RawOrigin::Signed(notary.clone()), T::MinimumStake::get())
+	verify {
+		let stake = Notaries::<T>::get(&notary).map(|info| info.stake);
+		assert_eq!(stake, Some(T::MinimumStake::get() + T::MinimumStake::get()));
+	}
+
+	rate {
+		let notary = setup_notary::<T>()?;
+		let client = setup_client::<T>(0);
+	}: _(RawOrigin::Signed(client.clone()), notary.clone(), MAX_SCORE)
+	verify {
+		let score = Ratings::<T>::get(&notary, &client).map(|rating| rating.score);
+		assert_eq!(score, Some(MAX_SCORE));
+	}
+
+	unregister {
+		let notary = setup_notary::<T>()?;
+	}: _(RawOrigin::Signed(notary.clone()))
+	verify {
+		assert!(Notaries::<T>::get(&notary).and_then(|info| info.unbonding_until).is_some());
+	}
+
+	withdraw_stake {
+		let r in 0 .. T::MaxRatings::get();
+		let notary = setup_notary::<T>()?;
+		for i in 0 .. r {
+			let client = setup_client::<T>(i);
+			Pallet::<T>::rate(RawOrigin::Signed(client).into(), notary.clone(), MAX_SCORE)?;
+		}
+		Pallet::<T>::unregister(RawOrigin::Signed(notary.clone()).into())?;
+		frame_system::Pallet::<T>::set_block_number(T::UnbondingPeriod::get() + 1u32.into());
+	}: _(RawOrigin::Signed(notary.clone()))
+	verify {
+		assert!(!Notaries::<T>::contains_key(&notary));
+		assert_eq!(Ratings::<T>::iter_prefix(&notary).count(), 0);
+	}
+
+	slash {
+		let notary = setup_notary::<T>()?;
+		let origin = T::SlashOrigin::successful_origin();
+	}: _<T::Origin>(origin, notary.clone(), T::MinimumStake::get())
+	verify {
+		let stake = Notaries::<T>::get(&notary).map(|info| info.stake);
+		assert_eq!(stake, Some(0u32.into()));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,353 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// A registry of professional notaries backed by a stake.
+///
+/// A notary registers by reserving at least `MinimumStake` and can be rated by other accounts,
+/// each reserving `RatingDeposit` until the notary withdraws their stake. `SlashOrigin`, such
+/// as a dispute resolution body, may slash the stake of a notary found to have attested
+/// fraudulently. Leaving the registry takes `UnbondingPeriod` blocks, during which the stake
+/// can still be slashed. Other pallets consult the [`NotaryRegistry`] trait, for example to
+/// give attestations by notaries more weight.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// Query the notary status of an account.
+pub trait NotaryRegistry<AccountId> {
+	/// Whether `who` is a registered notary in good standing.
+	fn is_notary(who: &AccountId) -> bool;
+}
+
+impl<AccountId> NotaryRegistry<AccountId> for () {
+	fn is_notary(_: &AccountId) -> bool {
+		false
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::{NotaryRegistry, WeightInfo};
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, OnUnbalanced, ReservableCurrency},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, Zero};
+	use sp_std::prelude::*;
+
+	/// The highest score a client can give a notary.
+	pub const MAX_SCORE: u8 = 5;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	pub type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+		<T as frame_system::Config>::AccountId,
+	>>::NegativeImbalance;
+
+	/// A registered notary.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct Notary<Balance, BlockNumber, Profile> {
+		/// The amount reserved from the notary.
+		pub stake: Balance,
+		/// Off-chain information about the notary, such as a license number or a URL.
+		pub profile: Profile,
+		/// The block from which the stake can be withdrawn, once the notary has unregistered.
+		pub unbonding_until: Option<BlockNumber>,
+	}
+
+	pub type NotaryOf<T> = Notary<
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+		BoundedVec<u8, <T as Config>::MaxProfileLen>,
+	>;
+
+	/// The score a client gave a notary.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct Rating<Balance> {
+		/// The score, between 1 and `MAX_SCORE`.
+		pub score: u8,
+		/// The amount reserved from the client.
+		pub deposit: Balance,
+	}
+
+	/// The aggregated ratings of a notary.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+	pub struct Reputation {
+		/// The sum of all scores given to the notary.
+		pub total: u32,
+		/// The number of clients that rated the notary.
+		pub count: u32,
+	}
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency used for stakes.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The origin allowed to slash notaries.
+		type SlashOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Handler for the slashed part of a stake.
+		type Slash: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The stake a notary in good standing must have reserved.
+		#[pallet::constant]
+		type MinimumStake: Get<BalanceOf<Self>>;
+
+		/// The number of blocks between unregistering and withdrawing the stake.
+		#[pallet::constant]
+		type UnbondingPeriod: Get<Self::BlockNumber>;
+
+		/// The maximum length of a notary profile.
+		#[pallet::constant]
+		type MaxProfileLen: Get<u32>;
+
+		/// The amount reserved from a client for every notary they rate.
+		#[pallet::constant]
+		type RatingDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum number of clients that can rate a notary.
+		#[pallet::constant]
+		type MaxRatings: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The registered notaries.
+	#[pallet::storage]
+	#[pallet::getter(fn notaries)]
+	pub type Notaries<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, NotaryOf<T>>;
+
+	/// The aggregated ratings of every notary. Removed when a former notary withdraws their stake.
+	#[pallet::storage]
+	#[pallet::getter(fn reputation)]
+	pub type Reputations<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Reputation, ValueQuery>;
+
+	/// The rating each client gave a notary.
+	#[pallet::storage]
+	#[pallet::getter(fn ratings)]
+	pub type Ratings<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		Rating<BalanceOf<T>>,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A notary registered. [notary, stake]
+		NotaryRegistered(T::AccountId, BalanceOf<T>),
+		/// A notary increased their stake. [notary, amount]
+		StakeBonded(T::AccountId, BalanceOf<T>),
+		/// A client rated a notary. [notary, client, score]
+		NotaryRated(T::AccountId, T::AccountId, u8),
+		/// A notary left the registry; the stake is locked until the given block. [notary, until]
+		NotaryUnregistered(T::AccountId, T::BlockNumber),
+		/// A former notary withdrew their stake. [notary, amount]
+		StakeWithdrawn(T::AccountId, BalanceOf<T>),
+		/// A notary was slashed. [notary, amount]
+		NotarySlashed(T::AccountId, BalanceOf<T>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is already registered as a notary.
+		AlreadyRegistered,
+		/// The account is not registered as a notary.
+		NotRegistered,
+		/// The stake is lower than `MinimumStake`.
+		StakeTooLow,
+		/// The profile is longer than `MaxProfileLen`.
+		ProfileTooLong,
+		/// The score is not between 1 and `MAX_SCORE`.
+		InvalidScore,
+		/// Notaries cannot rate themselves.
+		CannotRateSelf,
+		/// The notary has already been rated by `MaxRatings` clients.
+		TooManyRatings,
+		/// The notary has already unregistered.
+		Unbonding,
+		/// The notary has not unregistered.
+		NotUnbonding,
+		/// The unbonding period has not ended yet.
+		StillUnbonding,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register the caller as a notary, reserving `stake`.
+		#[pallet::weight(T::WeightInfo::register(profile.len() as u32))]
+		pub fn register(
+			origin: OriginFor<T>,
+			stake: BalanceOf<T>,
+			profile: Vec<u8>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Notaries::<T>::contains_key(&who), Error::<T>::AlreadyRegistered);
+			ensure!(stake >= T::MinimumStake::get(), Error::<T>::StakeTooLow);
+			let profile: BoundedVec<_, _> =
+				profile.try_into().map_err(|_| Error::<T>::ProfileTooLong)?;
+
+			T::Currency::reserve(&who, stake)?;
+			Notaries::<T>::insert(&who, Notary { stake, profile, unbonding_until: None });
+
+			Self::deposit_event(Event::NotaryRegistered(who, stake));
+			Ok(())
+		}
+
+		/// Reserve `amount` more for the caller's stake, for example to get back above
+		/// `MinimumStake` after a slash.
+		#[pallet::weight(T::WeightInfo::bond_extra())]
+		pub fn bond_extra(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			Notaries::<T>::try_mutate(&who, |maybe_notary| -> DispatchResult {
+				let notary = maybe_notary.as_mut().ok_or(Error::<T>::NotRegistered)?;
+				ensure!(notary.unbonding_until.is_none(), Error::<T>::Unbonding);
+
+				T::Currency::reserve(&who, amount)?;
+				notary.stake = notary.stake.saturating_add(amount);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::StakeBonded(who, amount));
+			Ok(())
+		}
+
+		/// Give `notary` a score between 1 and `MAX_SCORE`, replacing any earlier score by the
+		/// caller. `RatingDeposit` is reserved for the first score until the notary withdraws
+		/// their stake.
+		#[pallet::weight(T::WeightInfo::rate())]
+		pub fn rate(origin: OriginFor<T>, notary: T::AccountId, score: u8) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(score >= 1 && score <= MAX_SCORE, Error::<T>::InvalidScore);
+			ensure!(who != notary, Error::<T>::CannotRateSelf);
+			ensure!(Notaries::<T>::contains_key(&notary), Error::<T>::NotRegistered);
+
+			let previous = Ratings::<T>::get(&notary, &who);
+			let deposit = match &previous {
+				Some(rating) => rating.deposit,
+				None => {
+					let count = Reputations::<T>::get(&notary).count;
+					ensure!(count < T::MaxRatings::get(), Error::<T>::TooManyRatings);
+					let deposit = T::RatingDeposit::get();
+					T::Currency::reserve(&who, deposit)?;
+					deposit
+				},
+			};
+			Reputations::<T>::mutate(&notary, |reputation| {
+				match previous {
+					Some(old) =>
+						reputation.total = reputation.total.saturating_sub(old.score.into()),
+					None => reputation.count = reputation.count.saturating_add(1),
+				}
+				reputation.total = reputation.total.saturating_add(score.into());
+			});
+			Ratings::<T>::insert(&notary, &who, Rating { score, deposit });
+
+			Self::deposit_event(Event::NotaryRated(notary, who, score));
+			Ok(())
+		}
+
+		/// Leave the registry. The stake can be withdrawn after `UnbondingPeriod` blocks and
+		/// remains slashable until then.
+		#[pallet::weight(T::WeightInfo::unregister())]
+		pub fn unregister(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let until =
+				frame_system::Pallet::<T>::block_number().saturating_add(T::UnbondingPeriod::get());
+			Notaries::<T>::try_mutate(&who, |maybe_notary| -> DispatchResult {
+				let notary = maybe_notary.as_mut().ok_or(Error::<T>::NotRegistered)?;
+				ensure!(notary.unbonding_until.is_none(), Error::<T>::Unbonding);
+				notary.unbonding_until = Some(until);
+				Ok(())
+			})?;
+
+			Self::deposit_event(Event::NotaryUnregistered(who, until));
+			Ok(())
+		}
+
+		/// Release the stake of the caller once their unbonding period has ended. Their ratings
+		/// are removed and the deposits of the clients released.
+		#[pallet::weight(T::WeightInfo::withdraw_stake(T::MaxRatings::get()))]
+		pub fn withdraw_stake(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let notary = Notaries::<T>::get(&who).ok_or(Error::<T>::NotRegistered)?;
+			let until = notary.unbonding_until.ok_or(Error::<T>::NotUnbonding)?;
+			ensure!(frame_system::Pallet::<T>::block_number() >= until, Error::<T>::StillUnbonding);
+
+			T::Currency::unreserve(&who, notary.stake);
+			Notaries::<T>::remove(&who);
+			for (client, rating) in Ratings::<T>::drain_prefix(&who) {
+				T::Currency::unreserve(&client, rating.deposit);
+			}
+			Reputations::<T>::remove(&who);
+
+			Self::deposit_event(Event::StakeWithdrawn(who, notary.stake));
+			Ok(())
+		}
+
+		/// Slash up to `amount` of the stake of `notary`. A notary whose stake drops below
+		/// `MinimumStake` is no longer in good standing until they bond more.
+		#[pallet::weight(T::WeightInfo::slash())]
+		pub fn slash(
+			origin: OriginFor<T>,
+			notary: T::AccountId,
+			amount: BalanceOf<T>,
+		) -> DispatchResult {
+			T::SlashOrigin::ensure_origin(origin)?;
+
+			let slashed = Notaries::<T>::try_mutate(&notary, |maybe_notary| {
+				let info = maybe_notary.as_mut().ok_or(Error::<T>::NotRegistered)?;
+
+				let (imbalance, missing) =
+					T::Currency::slash_reserved(&notary, amount.min(info.stake));
+				T::Slash::on_unbalanced(imbalance);
+				let slashed = amount.min(info.stake).saturating_sub(missing);
+				info.stake = info.stake.saturating_sub(slashed);
+				Ok::<_, DispatchError>(slashed)
+			})?;
+
+			if !slashed.is_zero() {
+				Self::deposit_event(Event::NotarySlashed(notary, slashed));
+			}
+			Ok(())
+		}
+	}
+
+	impl<T: Config> NotaryRegistry<T::AccountId> for Pallet<T> {
+		fn is_notary(who: &T::AccountId) -> bool {
+			Notaries::<T>::get(who).map_or(false, |notary| {
+				notary.unbonding_until.is_none() && notary.stake >= T::MinimumStake::get()
+			})
+		}
+	}
+}
@@ -0,0 +1,123 @@
+use crate::{mock::*, Error, NotaryRegistry, Rating, Reputation};
+use frame_support::{assert_noop, assert_ok};
+
+#[test]
+fn register_reserves_stake() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Notaries::register(Origin::signed(1), 49, b"license".to_vec()),
+			Error::<Test>::StakeTooLow
+		);
+		assert_noop!(
+			Notaries::register(Origin::signed(1), 50, vec![0; 17]),
+			Error::<Test>::ProfileTooLong
+		);
+		assert_ok!(Notaries::register(Origin::signed(1), 50, b"license".to_vec()));
+		assert_noop!(
+			Notaries::register(Origin::signed(1), 50, b"license".to_vec()),
+			Error::<Test>::AlreadyRegistered
+		);
+		assert_eq!(Balances::reserved_balance(1), 50);
+		assert!(<Notaries as NotaryRegistry<u64>>::is_notary(&1));
+		assert!(!<Notaries as NotaryRegistry<u64>>::is_notary(&2));
+	});
+}
+
+#[test]
+fn rating_replaces_previous_score() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Notaries::register(Origin::signed(1), 50, vec![]));
+		assert_noop!(Notaries::rate(Origin::signed(1), 1, 5), Error::<Test>::CannotRateSelf);
+		assert_noop!(Notaries::rate(Origin::signed(2), 1, 0), Error::<Test>::InvalidScore);
+		assert_noop!(Notaries::rate(Origin::signed(2), 1, 6), Error::<Test>::InvalidScore);
+		assert_noop!(Notaries::rate(Origin::signed(2), 3, 5), Error::<Test>::NotRegistered);
+
+		assert_ok!(Notaries::rate(Origin::signed(2), 1, 5));
+		assert_ok!(Notaries::rate(Origin::signed(3), 1, 2));
+		assert_ok!(Notaries::rate(Origin::signed(2), 1, 1));
+		assert_eq!(Notaries::reputation(1), Reputation { total: 3, count: 2 });
+		// The deposit is only reserved for the first score.
+		assert_eq!(Balances::reserved_balance(2), 5);
+	});
+}
+
+#[test]
+fn ratings_reserve_a_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Notaries::register(Origin::signed(1), 50, vec![]));
+		// Account 4 cannot afford the deposit.
+		assert!(Notaries::rate(Origin::signed(4), 1, 5).is_err());
+
+		assert_ok!(Notaries::rate(Origin::signed(2), 1, 5));
+		assert_ok!(Notaries::rate(Origin::signed(3), 1, 4));
+		assert_eq!(Notaries::ratings(1, 2), Some(Rating { score: 5, deposit: 5 }));
+		assert_eq!(Balances::reserved_balance(3), 5);
+
+		assert_ok!(Balances::transfer(Origin::signed(2), 4, 10));
+		assert_noop!(Notaries::rate(Origin::signed(4), 1, 5), Error::<Test>::TooManyRatings);
+	});
+}
+
+#[test]
+fn withdrawing_removes_ratings() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Notaries::register(Origin::signed(1), 50, vec![]));
+		assert_ok!(Notaries::rate(Origin::signed(2), 1, 5));
+		assert_ok!(Notaries::rate(Origin::signed(3), 1, 4));
+
+		assert_ok!(Notaries::unregister(Origin::signed(1)));
+		System::set_block_number(11);
+		assert_ok!(Notaries::withdraw_stake(Origin::signed(1)));
+		assert_eq!(Notaries::ratings(1, 2), None);
+		assert_eq!(Notaries::ratings(1, 3), None);
+		assert_eq!(Notaries::reputation(1), Reputation::default());
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::reserved_balance(3), 0);
+	});
+}
+
+#[test]
+fn stake_is_locked_during_unbonding() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Notaries::register(Origin::signed(1), 60, vec![]));
+		assert_noop!(Notaries::withdraw_stake(Origin::signed(1)), Error::<Test>::NotUnbonding);
+
+		assert_ok!(Notaries::unregister(Origin::signed(1)));
+		assert!(!<Notaries as NotaryRegistry<u64>>::is_notary(&1));
+		assert_noop!(Notaries::unregister(Origin::signed(1)), Error::<Test>::Unbonding);
+		assert_noop!(Notaries::withdraw_stake(Origin::signed(1)), Error::<Test>::StillUnbonding);
+
+		// The stake stays slashable until it is withdrawn.
+		assert_ok!(Notaries::slash(Origin::root(), 1, 10));
+
+		System::set_block_number(11);
+		assert_ok!(Notaries::withdraw_stake(Origin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 90);
+		assert!(Notaries::notaries(1).is_none());
+	});
+}
+
+#[test]
+fn slashing_can_suspend_a_notary() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Notaries::register(Origin::signed(1), 60, vec![]));
+		assert_noop!(
+			Notaries::slash(Origin::signed(2), 1, 10),
+			sp_runtime::DispatchError::BadOrigin
+		);
+
+		assert_ok!(Notaries::slash(Origin::root(), 1, 20));
+		assert_eq!(Notaries::notaries(1).unwrap().stake, 40);
+		assert_eq!(Balances::reserved_balance(1), 40);
+		assert!(!<Notaries as NotaryRegistry<u64>>::is_notary(&1));
+
+		assert_ok!(Notaries::bond_extra(Origin::signed(1), 10));
+		assert!(<Notaries as NotaryRegistry<u64>>::is_notary(&1));
+
+		// Slashing more than the stake takes the whole stake.
+		assert_ok!(Notaries::slash(Origin::root(), 1, 1_000));
+		assert_eq!(Notaries::notaries(1).unwrap().stake, 0);
+		assert_eq!(Balances::total_balance(&1), 30);
+	});
+}
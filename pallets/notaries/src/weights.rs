@@ -0,0 +1,129 @@
+//! Placeholder weights for pallet_notaries
+//!
+//! NOT GENERATED: these are hand-written estimates in the format of the benchmark CLI, not
+//! measurements. Replace this file with the output of `node-template benchmark-all` on reference
+//! hardware before a release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_notaries.
+pub trait WeightInfo {
+	fn register(n: u32, ) -> Weight;
+	fn bond_extra() -> Weight;
+	fn rate() -> Weight;
+	fn unregister() -> Weight;
+	fn withdraw_stake(r: u32, ) -> Weight;
+	fn slash() -> Weight;
+}
+
+/// Placeholder weights for pallet_notaries, priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Notaries Notaries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn register(n: u32, ) -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Notaries Notaries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn bond_extra() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Notaries Notaries (r:1 w:0)
+	// Storage: Notaries Ratings (r:1 w:1)
+	// Storage: Notaries Reputations (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn rate() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Notaries Notaries (r:1 w:1)
+	fn unregister() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Notaries Notaries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Notaries Ratings (r:1 w:1)
+	// Storage: Notaries Reputations (r:0 w:1)
+	fn withdraw_stake(r: u32, ) -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add((16_000_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Notaries Notaries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn slash() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Notaries Notaries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn register(n: u32, ) -> Weight {
+		(42_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Notaries Notaries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn bond_extra() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Notaries Notaries (r:1 w:0)
+	// Storage: Notaries Ratings (r:1 w:1)
+	// Storage: Notaries Reputations (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn rate() -> Weight {
+		(48_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Notaries Notaries (r:1 w:1)
+	fn unregister() -> Weight {
+		(24_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Notaries Notaries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Notaries Ratings (r:1 w:1)
+	// Storage: Notaries Reputations (r:0 w:1)
+	fn withdraw_stake(r: u32, ) -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add((16_000_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Notaries Notaries (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn slash() -> Weight {
+		(45_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+}
@@ -0,0 +1,96 @@
+//! Benchmarking setup for pallet-organizations
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{sp_runtime::traits::Bounded, traits::Currency};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// An account holding enough funds to pay any deposit.
+fn funded_account<T: Config>(name: &'static str, index: u32) -> T::AccountId {
+	let who: T::AccountId = account(name, index, SEED);
+	T::Currency::make_free_balance_be(&who, BalanceOf::<T>::max_value() / 2u32.into());
+	who
+}
+
+/// Create organization `0`, owned by the whitelisted caller, with a role `0` allowing every
+/// permission.
+fn setup_organization<T: Config>() -> Result<T::AccountId, &'static str> {
+	let owner: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value() / 2u32.into());
+	Pallet::<T>::create_organization(RawOrigin::Signed(owner.clone()).into(), sp_std::vec![0; 8])?;
+	Pallet::<T>::set_role(RawOrigin::Signed(owner.clone()).into(), 0, 0, u32::MAX)?;
+	Ok(owner)
+}
+
+benchmarks! {
+	create_organization {
+		let n in 0 .. T::MaxNameLen::get();
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), sp_std::vec![0; n as usize])
+	verify {
+		assert_eq!(Organizations::<T>::get(0).map(|org| org.owner), Some(caller));
+	}
+
+	offer_ownership {
+		let owner = setup_organization::<T>()?;
+		let new_owner: T::AccountId = account("new_owner", 0, SEED);
+	}: _(RawOrigin::Signed(owner), 0, new_owner.clone())
+	verify {
+		assert_eq!(PendingOwners::<T>::get(0), Some(new_owner));
+	}
+
+	accept_ownership {
+		let owner = setup_organization::<T>()?;
+		let new_owner = funded_account::<T>("new_owner", 0);
+		Pallet::<T>::offer_ownership(RawOrigin::Signed(owner).into(), 0, new_owner.clone())?;
+	}: _(RawOrigin::Signed(new_owner.clone()), 0)
+	verify {
+		assert_eq!(Organizations::<T>::get(0).map(|org| org.owner), Some(new_owner));
+	}
+
+	dissolve_organization {
+		let m in 0 .. T::MaxMembers::get();
+		let r in 1 .. T::MaxRoles::get();
+		let owner = setup_organization::<T>()?;
+		for role in 1 .. r {
+			Pallet::<T>::set_role(RawOrigin::Signed(owner.clone()).into(), 0, role as RoleId, 0)?;
+		}
+		for i in 0 .. m {
+			let member: T::AccountId = account("member", i, SEED);
+			Pallet::<T>::set_member(RawOrigin::Signed(owner.clone()).into(), 0, member, 0)?;
+		}
+	}: _(RawOrigin::Signed(owner), 0)
+	verify {
+		assert!(Organizations::<T>::get(0).is_none());
+	}
+
+	set_role {
+		let owner = setup_organization::<T>()?;
+	}: _(RawOrigin::Signed(owner), 0, 1, Permission::CreateClaims.mask())
+	verify {
+		assert_eq!(Roles::<T>::get(0, 1), Some(Permission::CreateClaims.mask()));
+	}
+
+	set_member {
+		let owner = setup_organization::<T>()?;
+		let member: T::AccountId = account("member", 0, SEED);
+	}: _(RawOrigin::Signed(owner), 0, member.clone(), 0)
+	verify {
+		assert_eq!(Members::<T>::get(0, &member), Some(0));
+	}
+
+	remove_member {
+		let owner = setup_organization::<T>()?;
+		let member: T::AccountId = account("member", 0, SEED);
+		Pallet::<T>::set_member(RawOrigin::Signed(owner.clone()).into(), 0, member.clone(), 0)?;
+	}: _(RawOrigin::Signed(owner), 0, member.clone())
+	verify {
+		assert!(!Members::<T>::contains_key(0, &member));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,431 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Organizations with role-based access control.
+///
+/// Any account can found an organization by reserving `OrganizationDeposit`, except for
+/// `DepositExempt` accounts such as whitelisted public-good registrars. The owner defines
+/// up to `MaxRoles` roles as sets of [`Permission`]s and assigns exactly one role to every
+/// member. Other pallets consult the [`OrganizationPermissions`] trait so that assets such as
+/// claims can be owned by an organization and managed by whichever members hold the right
+/// role. Ownership changes hands in two steps, so the deposit is only ever reserved from an
+/// account that accepted it, and dissolving the organization returns the deposit to its owner.
+pub use pallet::*;
+
+use codec::{Decode, Encode};
+use frame_support::RuntimeDebug;
+use scale_info::TypeInfo;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// An action that a role may allow its holders to perform on behalf of an organization.
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum Permission {
+	/// Define roles and add or remove members.
+	ManageMembers,
+	/// Create claims owned by the organization.
+	CreateClaims,
+	/// Transfer claims owned by the organization.
+	TransferClaims,
+	/// Revoke claims owned by the organization.
+	RevokeClaims,
+}
+
+impl Permission {
+	/// The bit representing this permission in a role's permission mask.
+	pub fn mask(self) -> u32 {
+		1 << (self as u32)
+	}
+}
+
+/// Query whether an account may act on behalf of an organization.
+pub trait OrganizationPermissions<AccountId> {
+	/// The identifier of an organization.
+	type OrgId;
+
+	/// Whether `who` holds `permission` within `org`. Owners hold every permission.
+	fn has_permission(org: &Self::OrgId, who: &AccountId, permission: Permission) -> bool;
+
+	/// Whether `who` is the owner or a member of `org`.
+	fn is_member(org: &Self::OrgId, who: &AccountId) -> bool;
+}
+
+impl<AccountId> OrganizationPermissions<AccountId> for () {
+	type OrgId = u32;
+
+	fn has_permission(_: &Self::OrgId, _: &AccountId, _: Permission) -> bool {
+		false
+	}
+
+	fn is_member(_: &Self::OrgId, _: &AccountId) -> bool {
+		false
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::{OrganizationPermissions, Permission, WeightInfo};
+	use frame_support::{
+		pallet_prelude::*,
+		sp_runtime::traits::Zero,
+		traits::{Contains, Currency, ReservableCurrency},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_std::prelude::*;
+
+	/// The identifier of an organization.
+	pub type OrgId = u32;
+
+	/// The identifier of a role within an organization.
+	pub type RoleId = u16;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// An organization registered on chain.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct Organization<AccountId, Balance, Name> {
+		/// The account controlling the organization; it holds every permission.
+		pub owner: AccountId,
+		/// The amount reserved from the owner for registering the organization.
+		pub deposit: Balance,
+		/// A human readable name.
+		pub name: Name,
+		/// The number of members, not counting the owner.
+		pub members: u32,
+		/// The number of roles defined.
+		pub roles: u32,
+	}
+
+	pub type OrganizationOf<T> = Organization<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		BoundedVec<u8, <T as Config>::MaxNameLen>,
+	>;
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency used for organization deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The amount reserved from the founder of an organization.
+		#[pallet::constant]
+		type OrganizationDeposit: Get<BalanceOf<Self>>;
+
+		/// The accounts that own organizations without a deposit.
+		type DepositExempt: Contains<Self::AccountId>;
+
+		/// The maximum length of an organization name.
+		#[pallet::constant]
+		type MaxNameLen: Get<u32>;
+
+		/// The maximum number of members in one organization.
+		#[pallet::constant]
+		type MaxMembers: Get<u32>;
+
+		/// The maximum number of roles one organization can define.
+		#[pallet::constant]
+		type MaxRoles: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The identifier that will be given to the next organization.
+	#[pallet::storage]
+	#[pallet::getter(fn next_org_id)]
+	pub type NextOrgId<T> = StorageValue<_, OrgId, ValueQuery>;
+
+	/// The registered organizations.
+	#[pallet::storage]
+	#[pallet::getter(fn organizations)]
+	pub type Organizations<T: Config> = StorageMap<_, Twox64Concat, OrgId, OrganizationOf<T>>;
+
+	/// The account each organization has been offered to by its owner.
+	#[pallet::storage]
+	#[pallet::getter(fn pending_owners)]
+	pub type PendingOwners<T: Config> = StorageMap<_, Twox64Concat, OrgId, T::AccountId>;
+
+	/// The permission mask of every role defined by an organization.
+	#[pallet::storage]
+	#[pallet::getter(fn roles)]
+	pub type Roles<T> = StorageDoubleMap<_, Twox64Concat, OrgId, Twox64Concat, RoleId, u32>;
+
+	/// The role held by each member of an organization.
+	#[pallet::storage]
+	#[pallet::getter(fn members)]
+	pub type Members<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, OrgId, Blake2_128Concat, T::AccountId, RoleId>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An organization was created. [org, owner]
+		OrganizationCreated(OrgId, T::AccountId),
+		/// The owner offered the organization to another account. [org, new_owner]
+		OwnershipOffered(OrgId, T::AccountId),
+		/// The ownership of an organization changed. [org, new_owner]
+		OwnershipTransferred(OrgId, T::AccountId),
+		/// An organization was dissolved and its deposit returned. [org, owner]
+		OrganizationDissolved(OrgId, T::AccountId),
+		/// A role was defined or its permissions changed. [org, role, permissions]
+		RoleSet(OrgId, RoleId, u32),
+		/// A member was added or given another role. [org, who, role]
+		MemberSet(OrgId, T::AccountId, RoleId),
+		/// A member was removed from an organization. [org, who]
+		MemberRemoved(OrgId, T::AccountId),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The organization does not exist.
+		UnknownOrganization,
+		/// The role has not been defined by the organization.
+		UnknownRole,
+		/// The account is not a member of the organization.
+		NotMember,
+		/// The caller lacks the permission required for this action.
+		NoPermission,
+		/// Only the owner of the organization may perform this action.
+		NotOwner,
+		/// The name is longer than `MaxNameLen`.
+		NameTooLong,
+		/// The organization already has `MaxMembers` members.
+		TooManyMembers,
+		/// The organization already has `MaxRoles` roles.
+		TooManyRoles,
+		/// The organization has not been offered to the caller.
+		NotOffered,
+		/// No more organization identifiers are available.
+		NoAvailableOrgId,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Found a new organization owned by the caller, reserving `OrganizationDeposit` unless
+		/// the caller is `DepositExempt`.
+		#[pallet::weight(T::WeightInfo::create_organization(name.len() as u32))]
+		pub fn create_organization(origin: OriginFor<T>, name: Vec<u8>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let name: BoundedVec<_, _> = name.try_into().map_err(|_| Error::<T>::NameTooLong)?;
+			let org = NextOrgId::<T>::get();
+			let next = org.checked_add(1).ok_or(Error::<T>::NoAvailableOrgId)?;
+
+			let deposit = Self::deposit_of(&who);
+			T::Currency::reserve(&who, deposit)?;
+
+			Organizations::<T>::insert(
+				org,
+				Organization { owner: who.clone(), deposit, name, members: 0, roles: 0 },
+			);
+			NextOrgId::<T>::put(next);
+
+			Self::deposit_event(Event::OrganizationCreated(org, who));
+			Ok(())
+		}
+
+		/// Offer the organization to `new_owner`, who takes it over by calling
+		/// `accept_ownership`. A later offer replaces an earlier one.
+		#[pallet::weight(T::WeightInfo::offer_ownership())]
+		pub fn offer_ownership(
+			origin: OriginFor<T>,
+			org: OrgId,
+			new_owner: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let info = Organizations::<T>::get(org).ok_or(Error::<T>::UnknownOrganization)?;
+			ensure!(info.owner == who, Error::<T>::NotOwner);
+			PendingOwners::<T>::insert(org, &new_owner);
+
+			Self::deposit_event(Event::OwnershipOffered(org, new_owner));
+			Ok(())
+		}
+
+		/// Take over an organization offered to the caller, reserving the deposit from the
+		/// caller and returning the deposit of the previous owner.
+		#[pallet::weight(T::WeightInfo::accept_ownership())]
+		pub fn accept_ownership(origin: OriginFor<T>, org: OrgId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(PendingOwners::<T>::get(org) == Some(who.clone()), Error::<T>::NotOffered);
+			Organizations::<T>::try_mutate(org, |maybe_org| -> DispatchResult {
+				let info = maybe_org.as_mut().ok_or(Error::<T>::UnknownOrganization)?;
+
+				let deposit = Self::deposit_of(&who);
+				T::Currency::reserve(&who, deposit)?;
+				T::Currency::unreserve(&info.owner, info.deposit);
+				info.owner = who.clone();
+				info.deposit = deposit;
+				Ok(())
+			})?;
+			PendingOwners::<T>::remove(org);
+
+			Self::deposit_event(Event::OwnershipTransferred(org, who));
+			Ok(())
+		}
+
+		/// Dissolve the organization, removing its roles and members, and return the deposit.
+		///
+		/// The dispatch origin must be the owner.
+		#[pallet::weight(T::WeightInfo::dissolve_organization(
+			T::MaxMembers::get(),
+			T::MaxRoles::get()
+		))]
+		pub fn dissolve_organization(origin: OriginFor<T>, org: OrgId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let info = Organizations::<T>::get(org).ok_or(Error::<T>::UnknownOrganization)?;
+			ensure!(info.owner == who, Error::<T>::NotOwner);
+
+			Members::<T>::remove_prefix(org, None);
+			Roles::<T>::remove_prefix(org, None);
+			PendingOwners::<T>::remove(org);
+			Organizations::<T>::remove(org);
+			T::Currency::unreserve(&who, info.deposit);
+
+			Self::deposit_event(Event::OrganizationDissolved(org, who));
+			Ok(())
+		}
+
+		/// Define `role` with the given permission mask, or change the permissions of an existing
+		/// role. An organization can define at most `MaxRoles` roles. Requires
+		/// [`Permission::ManageMembers`].
+		#[pallet::weight(T::WeightInfo::set_role())]
+		pub fn set_role(
+			origin: OriginFor<T>,
+			org: OrgId,
+			role: RoleId,
+			permissions: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_permission(org, &who, Permission::ManageMembers)?;
+
+			if !Roles::<T>::contains_key(org, role) {
+				Organizations::<T>::try_mutate(org, |maybe_org| -> DispatchResult {
+					let info = maybe_org.as_mut().ok_or(Error::<T>::UnknownOrganization)?;
+					ensure!(info.roles < T::MaxRoles::get(), Error::<T>::TooManyRoles);
+					info.roles += 1;
+					Ok(())
+				})?;
+			}
+			Roles::<T>::insert(org, role, permissions);
+
+			Self::deposit_event(Event::RoleSet(org, role, permissions));
+			Ok(())
+		}
+
+		/// Add `member` to the organization with `role`, or move an existing member to `role`.
+		/// Requires [`Permission::ManageMembers`].
+		#[pallet::weight(T::WeightInfo::set_member())]
+		pub fn set_member(
+			origin: OriginFor<T>,
+			org: OrgId,
+			member: T::AccountId,
+			role: RoleId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_permission(org, &who, Permission::ManageMembers)?;
+			ensure!(Roles::<T>::contains_key(org, role), Error::<T>::UnknownRole);
+
+			if !Members::<T>::contains_key(org, &member) {
+				Organizations::<T>::try_mutate(org, |maybe_org| -> DispatchResult {
+					let info = maybe_org.as_mut().ok_or(Error::<T>::UnknownOrganization)?;
+					ensure!(info.members < T::MaxMembers::get(), Error::<T>::TooManyMembers);
+					info.members += 1;
+					Ok(())
+				})?;
+			}
+			Members::<T>::insert(org, &member, role);
+
+			Self::deposit_event(Event::MemberSet(org, member, role));
+			Ok(())
+		}
+
+		/// Remove `member` from the organization. Requires [`Permission::ManageMembers`], unless
+		/// the caller is removing themselves.
+		#[pallet::weight(T::WeightInfo::remove_member())]
+		pub fn remove_member(
+			origin: OriginFor<T>,
+			org: OrgId,
+			member: T::AccountId,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if who != member {
+				Self::ensure_permission(org, &who, Permission::ManageMembers)?;
+			}
+			ensure!(Members::<T>::contains_key(org, &member), Error::<T>::NotMember);
+
+			Members::<T>::remove(org, &member);
+			Organizations::<T>::mutate(org, |maybe_org| {
+				if let Some(info) = maybe_org {
+					info.members = info.members.saturating_sub(1);
+				}
+			});
+
+			Self::deposit_event(Event::MemberRemoved(org, member));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The deposit reserved from `who` for owning an organization.
+		fn deposit_of(who: &T::AccountId) -> BalanceOf<T> {
+			if T::DepositExempt::contains(who) {
+				Zero::zero()
+			} else {
+				T::OrganizationDeposit::get()
+			}
+		}
+
+		/// Ensure that `who` holds `permission` within `org`.
+		pub fn ensure_permission(
+			org: OrgId,
+			who: &T::AccountId,
+			permission: Permission,
+		) -> DispatchResult {
+			ensure!(Organizations::<T>::contains_key(org), Error::<T>::UnknownOrganization);
+			ensure!(Self::has_permission(&org, who, permission), Error::<T>::NoPermission);
+			Ok(())
+		}
+	}
+
+	impl<T: Config> OrganizationPermissions<T::AccountId> for Pallet<T> {
+		type OrgId = OrgId;
+
+		fn has_permission(org: &OrgId, who: &T::AccountId, permission: Permission) -> bool {
+			match Organizations::<T>::get(org) {
+				Some(info) if info.owner == *who => true,
+				Some(_) => Members::<T>::get(org, who)
+					.and_then(|role| Roles::<T>::get(org, role))
+					.map_or(false, |mask| mask & permission.mask() != 0),
+				None => false,
+			}
+		}
+
+		fn is_member(org: &OrgId, who: &T::AccountId) -> bool {
+			match Organizations::<T>::get(org) {
+				Some(info) => info.owner == *who || Members::<T>::contains_key(org, who),
+				None => false,
+			}
+		}
+	}
+}
@@ -0,0 +1,158 @@
+use crate::{mock::*, Error, OrganizationPermissions, Permission};
+use frame_support::{assert_noop, assert_ok};
+
+const MANAGER: u16 = 0;
+const CLERK: u16 = 1;
+
+fn setup_org() {
+	assert_ok!(Organizations::create_organization(Origin::signed(1), b"acme".to_vec()));
+	assert_ok!(Organizations::set_role(
+		Origin::signed(1),
+		0,
+		MANAGER,
+		Permission::ManageMembers.mask() | Permission::TransferClaims.mask()
+	));
+	assert_ok!(Organizations::set_role(
+		Origin::signed(1),
+		0,
+		CLERK,
+		Permission::CreateClaims.mask()
+	));
+}
+
+#[test]
+fn create_organization_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Organizations::create_organization(Origin::signed(1), vec![0; 17]),
+			Error::<Test>::NameTooLong
+		);
+		assert_ok!(Organizations::create_organization(Origin::signed(1), b"acme".to_vec()));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(Organizations::organizations(0).unwrap().owner, 1);
+		assert_eq!(Organizations::next_org_id(), 1);
+	});
+}
+
+#[test]
+fn exempt_accounts_own_organizations_without_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Organizations::create_organization(Origin::signed(EXEMPT), b"acme".to_vec()));
+		assert_eq!(Organizations::organizations(0).unwrap().deposit, 0);
+
+		// The deposit is taken once the organization passes to an account that is not exempt.
+		assert_ok!(Organizations::offer_ownership(Origin::signed(EXEMPT), 0, 1));
+		assert_ok!(Organizations::accept_ownership(Origin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(1), 10);
+		assert_eq!(Organizations::organizations(0).unwrap().deposit, 10);
+	});
+}
+
+#[test]
+fn roles_grant_permissions() {
+	new_test_ext().execute_with(|| {
+		setup_org();
+		assert_ok!(Organizations::set_member(Origin::signed(1), 0, 2, CLERK));
+
+		assert!(Organizations::is_member(&0, &2));
+		assert!(Organizations::has_permission(&0, &2, Permission::CreateClaims));
+		assert!(!Organizations::has_permission(&0, &2, Permission::RevokeClaims));
+		// The owner holds every permission without being a member.
+		assert!(Organizations::has_permission(&0, &1, Permission::RevokeClaims));
+
+		assert_noop!(
+			Organizations::set_member(Origin::signed(2), 0, 3, CLERK),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Organizations::set_member(Origin::signed(1), 0, 3, 7),
+			Error::<Test>::UnknownRole
+		);
+	});
+}
+
+#[test]
+fn member_count_is_bounded() {
+	new_test_ext().execute_with(|| {
+		setup_org();
+		assert_ok!(Organizations::set_member(Origin::signed(1), 0, 2, MANAGER));
+		assert_ok!(Organizations::set_member(Origin::signed(2), 0, 3, CLERK));
+		// Changing the role of an existing member does not count against the limit.
+		assert_ok!(Organizations::set_member(Origin::signed(2), 0, 3, MANAGER));
+		assert_noop!(
+			Organizations::set_member(Origin::signed(2), 0, 4, CLERK),
+			Error::<Test>::TooManyMembers
+		);
+
+		assert_ok!(Organizations::remove_member(Origin::signed(3), 0, 3));
+		assert!(!Organizations::is_member(&0, &3));
+		assert_eq!(Organizations::organizations(0).unwrap().members, 1);
+	});
+}
+
+#[test]
+fn role_count_is_bounded() {
+	new_test_ext().execute_with(|| {
+		setup_org();
+		assert_ok!(Organizations::set_role(Origin::signed(1), 0, 2, 0));
+		// Changing an existing role does not count against the limit.
+		assert_ok!(Organizations::set_role(Origin::signed(1), 0, CLERK, 0));
+		assert_noop!(
+			Organizations::set_role(Origin::signed(1), 0, 3, 0),
+			Error::<Test>::TooManyRoles
+		);
+		assert_eq!(Organizations::organizations(0).unwrap().roles, 3);
+	});
+}
+
+#[test]
+fn ownership_moves_with_the_deposit_once_accepted() {
+	new_test_ext().execute_with(|| {
+		setup_org();
+		assert_noop!(
+			Organizations::offer_ownership(Origin::signed(2), 0, 2),
+			Error::<Test>::NotOwner
+		);
+		// Nobody can take over an organization, or its deposit, without an offer.
+		assert_noop!(
+			Organizations::accept_ownership(Origin::signed(2), 0),
+			Error::<Test>::NotOffered
+		);
+
+		assert_ok!(Organizations::offer_ownership(Origin::signed(1), 0, 2));
+		// The offer reserves nothing.
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_noop!(
+			Organizations::accept_ownership(Origin::signed(3), 0),
+			Error::<Test>::NotOffered
+		);
+
+		assert_ok!(Organizations::accept_ownership(Origin::signed(2), 0));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::reserved_balance(2), 10);
+		assert!(!Organizations::has_permission(&0, &1, Permission::ManageMembers));
+		assert_eq!(Organizations::pending_owners(0), None);
+	});
+}
+
+#[test]
+fn dissolve_organization_returns_deposit() {
+	new_test_ext().execute_with(|| {
+		setup_org();
+		assert_ok!(Organizations::set_member(Origin::signed(1), 0, 2, MANAGER));
+		assert_noop!(
+			Organizations::dissolve_organization(Origin::signed(2), 0),
+			Error::<Test>::NotOwner
+		);
+
+		assert_ok!(Organizations::dissolve_organization(Origin::signed(1), 0));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Organizations::organizations(0), None);
+		assert_eq!(Organizations::roles(0, MANAGER), None);
+		assert!(!Organizations::is_member(&0, &2));
+		assert_noop!(
+			Organizations::dissolve_organization(Origin::signed(1), 0),
+			Error::<Test>::UnknownOrganization
+		);
+	});
+}
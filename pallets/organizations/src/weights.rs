@@ -0,0 +1,158 @@
+//! Placeholder weights for pallet_organizations
+//!
+//! NOT GENERATED: these are hand-written estimates in the format of the benchmark CLI, not
+//! measurements. Replace this file with the output of `node-template benchmark-all` on reference
+//! hardware before a release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_organizations.
+pub trait WeightInfo {
+	fn create_organization(n: u32, ) -> Weight;
+	fn offer_ownership() -> Weight;
+	fn accept_ownership() -> Weight;
+	fn dissolve_organization(m: u32, r: u32, ) -> Weight;
+	fn set_role() -> Weight;
+	fn set_member() -> Weight;
+	fn remove_member() -> Weight;
+}
+
+/// Placeholder weights for pallet_organizations, priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Organizations NextOrgId (r:1 w:1)
+	// Storage: Whitelist Allowances (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Organizations Organizations (r:0 w:1)
+	fn create_organization(n: u32, ) -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Organizations Organizations (r:1 w:0)
+	// Storage: Organizations PendingOwners (r:0 w:1)
+	fn offer_ownership() -> Weight {
+		(23_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Organizations PendingOwners (r:1 w:1)
+	// Storage: Organizations Organizations (r:1 w:1)
+	// Storage: Whitelist Allowances (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn accept_ownership() -> Weight {
+		(58_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Organizations Organizations (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Organizations Members (r:0 w:1)
+	// Storage: Organizations Roles (r:0 w:1)
+	// Storage: Organizations PendingOwners (r:0 w:1)
+	fn dissolve_organization(m: u32, r: u32, ) -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add((1_300_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add((1_300_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Organizations Organizations (r:2 w:1)
+	// Storage: Organizations Members (r:1 w:0)
+	// Storage: Organizations Roles (r:1 w:1)
+	fn set_role() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Organizations Organizations (r:2 w:1)
+	// Storage: Organizations Members (r:2 w:1)
+	// Storage: Organizations Roles (r:1 w:0)
+	fn set_member() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Organizations Organizations (r:2 w:1)
+	// Storage: Organizations Members (r:2 w:1)
+	fn remove_member() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Organizations NextOrgId (r:1 w:1)
+	// Storage: Whitelist Allowances (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Organizations Organizations (r:0 w:1)
+	fn create_organization(n: u32, ) -> Weight {
+		(44_000_000 as Weight)
+			.saturating_add((2_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Organizations Organizations (r:1 w:0)
+	// Storage: Organizations PendingOwners (r:0 w:1)
+	fn offer_ownership() -> Weight {
+		(23_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Organizations PendingOwners (r:1 w:1)
+	// Storage: Organizations Organizations (r:1 w:1)
+	// Storage: Whitelist Allowances (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	fn accept_ownership() -> Weight {
+		(58_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
+	}
+	// Storage: Organizations Organizations (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Organizations Members (r:0 w:1)
+	// Storage: Organizations Roles (r:0 w:1)
+	// Storage: Organizations PendingOwners (r:0 w:1)
+	fn dissolve_organization(m: u32, r: u32, ) -> Weight {
+		(41_000_000 as Weight)
+			.saturating_add((1_300_000 as Weight).saturating_mul(m as Weight))
+			.saturating_add((1_300_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Organizations Organizations (r:2 w:1)
+	// Storage: Organizations Members (r:1 w:0)
+	// Storage: Organizations Roles (r:1 w:1)
+	fn set_role() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Organizations Organizations (r:2 w:1)
+	// Storage: Organizations Members (r:2 w:1)
+	// Storage: Organizations Roles (r:1 w:0)
+	fn set_member() -> Weight {
+		(34_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Organizations Organizations (r:2 w:1)
+	// Storage: Organizations Members (r:2 w:1)
+	fn remove_member() -> Weight {
+		(32_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+}
@@ -0,0 +1,19 @@
+//! Benchmarking setup for pallet-parameters
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_support::traits::EnsureOrigin;
+use sp_std::vec;
+
+benchmarks! {
+	set_parameter {
+		let origin = T::ParameterOrigin::successful_origin();
+		let key = vec![b'k'; T::MaxKeyLen::get() as usize];
+	}: _<T::Origin>(origin, key.clone(), Some(100))
+	verify {
+		assert_eq!(Pallet::<T>::parameter(&key), Some(100));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
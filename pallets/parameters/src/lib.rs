@@ -0,0 +1,118 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Runtime parameters governed on-chain.
+///
+/// `ParameterOrigin` sets named integer parameters. The runtime passes a [`ParameterOr`]
+/// instead of a constant to the pallets it configures, which then reads the parameter on every
+/// access and falls back to the compiled-in default when the parameter is unset or out of
+/// range. This way governance can tune values such as deposits or periods without a runtime
+/// upgrade.
+pub use pallet::*;
+
+use frame_support::traits::Get;
+use sp_std::marker::PhantomData;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::WeightInfo;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+	use sp_std::prelude::*;
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin allowed to set parameters.
+		type ParameterOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The maximum length of a parameter name.
+		#[pallet::constant]
+		type MaxKeyLen: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The parameters set by `ParameterOrigin`, keyed by name.
+	#[pallet::storage]
+	pub type Parameters<T: Config> =
+		StorageMap<_, Blake2_128Concat, BoundedVec<u8, T::MaxKeyLen>, u128>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A parameter was set, or reset to its default if the value is `None`. [key, value]
+		ParameterSet(Vec<u8>, Option<u128>),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The parameter name is longer than `MaxKeyLen`.
+		KeyTooLong,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the parameter `key` to `value`, or reset it to the runtime default if `value` is
+		/// `None`. The dispatch origin must be `ParameterOrigin`.
+		#[pallet::weight(T::WeightInfo::set_parameter())]
+		pub fn set_parameter(
+			origin: OriginFor<T>,
+			key: Vec<u8>,
+			value: Option<u128>,
+		) -> DispatchResult {
+			T::ParameterOrigin::ensure_origin(origin)?;
+
+			let bounded: BoundedVec<_, _> =
+				key.clone().try_into().map_err(|_| Error::<T>::KeyTooLong)?;
+			Parameters::<T>::set(bounded, value);
+
+			Self::deposit_event(Event::ParameterSet(key, value));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The value of the parameter `key`, if it is set.
+		pub fn parameter(key: &[u8]) -> Option<u128> {
+			let key: BoundedVec<_, T::MaxKeyLen> = key.to_vec().try_into().ok()?;
+			Parameters::<T>::get(key)
+		}
+	}
+}
+
+/// Read the parameter named `Key`, or `Fallback` if it is unset or does not fit in `V`.
+pub struct ParameterOr<T, Key, Fallback>(PhantomData<(T, Key, Fallback)>);
+
+impl<T, Key, Fallback, V> Get<V> for ParameterOr<T, Key, Fallback>
+where
+	T: Config,
+	Key: Get<&'static [u8]>,
+	Fallback: Get<V>,
+	V: TryFrom<u128>,
+{
+	fn get() -> V {
+		Pallet::<T>::parameter(Key::get())
+			.and_then(|value| V::try_from(value).ok())
+			.unwrap_or_else(Fallback::get)
+	}
+}
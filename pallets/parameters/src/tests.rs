@@ -0,0 +1,48 @@
+use crate::{mock::*, Error, ParameterOr};
+use frame_support::{assert_noop, assert_ok, parameter_types, traits::Get};
+use sp_runtime::traits::BadOrigin;
+
+parameter_types! {
+	pub PeriodKey: &'static [u8] = b"Period";
+	pub const DefaultPeriod: u32 = 10;
+}
+
+type Period = ParameterOr<Test, PeriodKey, DefaultPeriod>;
+
+#[test]
+fn only_parameter_origin_sets_parameters() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Parameters::set_parameter(Origin::signed(1), b"Period".to_vec(), Some(1)),
+			BadOrigin
+		);
+		assert_noop!(
+			Parameters::set_parameter(Origin::root(), b"TooLongKey".to_vec(), Some(1)),
+			Error::<Test>::KeyTooLong
+		);
+		assert_ok!(Parameters::set_parameter(Origin::root(), b"Period".to_vec(), Some(20)));
+		assert_eq!(Parameters::parameter(b"Period"), Some(20));
+	});
+}
+
+#[test]
+fn parameters_override_the_default() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(Period::get(), 10);
+
+		assert_ok!(Parameters::set_parameter(Origin::root(), b"Period".to_vec(), Some(20)));
+		assert_eq!(Period::get(), 20);
+
+		assert_ok!(Parameters::set_parameter(Origin::root(), b"Period".to_vec(), None));
+		assert_eq!(Period::get(), 10);
+	});
+}
+
+#[test]
+fn out_of_range_parameters_use_the_default() {
+	new_test_ext().execute_with(|| {
+		let too_large = u32::MAX as u128 + 1;
+		assert_ok!(Parameters::set_parameter(Origin::root(), b"Period".to_vec(), Some(too_large)));
+		assert_eq!(Period::get(), 10);
+	});
+}
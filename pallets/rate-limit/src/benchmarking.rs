@@ -0,0 +1,17 @@
+//! Benchmarking setup for pallet-rate-limit
+
+use super::*;
+
+use frame_benchmarking::benchmarks;
+use frame_support::traits::EnsureOrigin;
+
+benchmarks! {
+	set_limits {
+		let origin = T::LimitOrigin::successful_origin();
+	}: _<T::Origin>(origin, 10, 100)
+	verify {
+		assert_eq!(Limits::<T>::get(), RateLimits { per_block: 10, per_era: 100 });
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
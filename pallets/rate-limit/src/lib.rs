@@ -0,0 +1,290 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Per-account transaction rate limits.
+///
+/// `LimitOrigin` sets how many signed transactions one account may have included per block
+/// and per era of `EraLength` blocks. The [`CheckRateLimit`] signed extension enforces the
+/// limits independently of fees, for normal dispatches only. The transaction pool rejects
+/// transactions beyond the per-era limit, and block authors defer those beyond the per-block
+/// limit to a later block. The usage recorded for past eras is removed in `on_idle`.
+pub use pallet::*;
+
+use codec::{Decode, Encode};
+use frame_support::weights::{DispatchClass, DispatchInfo};
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, SignedExtension},
+	transaction_validity::{
+		InvalidTransaction, TransactionValidity, TransactionValidityError, ValidTransaction,
+	},
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+/// The custom `InvalidTransaction` code of transactions beyond the per-era limit.
+pub const ERA_LIMIT_EXCEEDED: u8 = 0x1e;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::WeightInfo;
+	use frame_support::{pallet_prelude::*, storage::KillStorageResult};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{One, Zero};
+
+	/// The number of transactions an account may have included. Zero means unlimited.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+	pub struct RateLimits {
+		/// The limit per block.
+		pub per_block: u32,
+		/// The limit per era.
+		pub per_era: u32,
+	}
+
+	/// The transactions an account had included in an era.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, Default, RuntimeDebug, TypeInfo)]
+	pub struct Usage<BlockNumber> {
+		/// The block `in_block` refers to.
+		pub block: BlockNumber,
+		/// The number of transactions included in `block`.
+		pub in_block: u32,
+		/// The number of transactions included in the era.
+		pub in_era: u32,
+	}
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin allowed to change the limits.
+		type LimitOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The number of blocks in an era.
+		#[pallet::constant]
+		type EraLength: Get<Self::BlockNumber>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The limits applying to every account.
+	#[pallet::storage]
+	#[pallet::getter(fn limits)]
+	pub type Limits<T> = StorageValue<_, RateLimits, ValueQuery>;
+
+	/// The transactions of every account, keyed by era index and account.
+	#[pallet::storage]
+	#[pallet::getter(fn usage)]
+	pub type Usages<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::BlockNumber,
+		Blake2_128Concat,
+		T::AccountId,
+		Usage<T::BlockNumber>,
+		ValueQuery,
+	>;
+
+	/// The oldest era whose usage may not have been removed yet.
+	#[pallet::storage]
+	pub type OldestEra<T: Config> = StorageValue<_, T::BlockNumber, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The limits changed. [per_block, per_era]
+		LimitsSet(u32, u32),
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			let db = T::DbWeight::get();
+			// Reading and writing the oldest era.
+			let mut used = db.reads_writes(1, 1);
+			if used > remaining_weight {
+				return 0
+			}
+
+			let era = Self::current_era();
+			let mut oldest = OldestEra::<T>::get();
+			while oldest < era {
+				// Every era costs a read of its prefix, and a write per account removed.
+				let left = remaining_weight.saturating_sub(used).saturating_sub(db.reads(1));
+				let limit = (left / db.writes(1).max(1)).min(u32::MAX as Weight) as u32;
+				if limit == 0 {
+					break
+				}
+				used = used.saturating_add(db.reads(1));
+				match Usages::<T>::remove_prefix(oldest, Some(limit)) {
+					KillStorageResult::AllRemoved(removed) => {
+						used = used.saturating_add(db.writes(removed.into()));
+						oldest += One::one();
+					},
+					KillStorageResult::SomeRemaining(removed) => {
+						used = used.saturating_add(db.writes(removed.into()));
+						break
+					},
+				}
+			}
+			OldestEra::<T>::put(oldest);
+
+			used
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Set the number of transactions an account may have included per block and per era;
+		/// zero disables a limit. The dispatch origin must be `LimitOrigin`.
+		#[pallet::weight(T::WeightInfo::set_limits())]
+		pub fn set_limits(origin: OriginFor<T>, per_block: u32, per_era: u32) -> DispatchResult {
+			T::LimitOrigin::ensure_origin(origin)?;
+
+			Limits::<T>::put(RateLimits { per_block, per_era });
+
+			Self::deposit_event(Event::LimitsSet(per_block, per_era));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The index of the current era.
+		fn current_era() -> T::BlockNumber {
+			let length = T::EraLength::get();
+			if length.is_zero() {
+				Zero::zero()
+			} else {
+				<frame_system::Pallet<T>>::block_number() / length
+			}
+		}
+
+		/// The usage of `who` in the current block and era.
+		pub fn current_usage(who: &T::AccountId) -> Usage<T::BlockNumber> {
+			let block = <frame_system::Pallet<T>>::block_number();
+			let mut usage = Usages::<T>::get(Self::current_era(), who);
+			if usage.block != block {
+				usage.block = block;
+				usage.in_block = 0;
+			}
+			usage
+		}
+
+		/// Whether `usage` leaves room for another transaction in the current era, and in the
+		/// current block.
+		pub fn within_limits(usage: &Usage<T::BlockNumber>) -> (bool, bool) {
+			let limits = Limits::<T>::get();
+			let within = |used: u32, limit: u32| limit == 0 || used < limit;
+			(within(usage.in_era, limits.per_era), within(usage.in_block, limits.per_block))
+		}
+
+		/// Record another transaction included for `who`.
+		pub fn record(who: &T::AccountId, mut usage: Usage<T::BlockNumber>) {
+			usage.in_block = usage.in_block.saturating_add(1);
+			usage.in_era = usage.in_era.saturating_add(1);
+			Usages::<T>::insert(Self::current_era(), who, usage);
+		}
+	}
+}
+
+/// Reject signed transactions from accounts that exceeded the limits in [`Limits`].
+///
+/// The pool rejects transactions beyond the per-era limit with the custom error
+/// [`ERA_LIMIT_EXCEEDED`]. While a block is built, exceeding either limit reports
+/// `ExhaustsResources` instead: the author skips the transaction but keeps it in the pool, so
+/// it is included in a later block, and so are the later nonces of the account. A transaction
+/// kept beyond the per-era limit is dropped when the pool revalidates it, unless the era
+/// ended by then. Operational and mandatory dispatches are not limited.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct CheckRateLimit<T: Config + Send + Sync>(sp_std::marker::PhantomData<T>);
+
+impl<T: Config + Send + Sync> CheckRateLimit<T> {
+	/// Create a new instance of the extension.
+	pub fn new() -> Self {
+		Self(Default::default())
+	}
+}
+
+impl<T: Config + Send + Sync> Default for CheckRateLimit<T> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for CheckRateLimit<T> {
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "CheckRateLimit")
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for CheckRateLimit<T>
+where
+	T::Call: Dispatchable<Info = DispatchInfo>,
+{
+	const IDENTIFIER: &'static str = "CheckRateLimit";
+	type AccountId = T::AccountId;
+	type Call = T::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		_call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		if info.class != DispatchClass::Normal {
+			return Ok(ValidTransaction::default())
+		}
+
+		let (within_era, _) = Pallet::<T>::within_limits(&Pallet::<T>::current_usage(who));
+		if within_era {
+			Ok(ValidTransaction::default())
+		} else {
+			Err(InvalidTransaction::Custom(ERA_LIMIT_EXCEEDED).into())
+		}
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		_call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<(), TransactionValidityError> {
+		// Do not track accounts while no limit is set.
+		if info.class != DispatchClass::Normal || Limits::<T>::get() == RateLimits::default() {
+			return Ok(())
+		}
+
+		let usage = Pallet::<T>::current_usage(who);
+		match Pallet::<T>::within_limits(&usage) {
+			(true, true) => {
+				Pallet::<T>::record(who, usage);
+				Ok(())
+			},
+			_ => Err(InvalidTransaction::ExhaustsResources.into()),
+		}
+	}
+}
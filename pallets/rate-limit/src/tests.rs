@@ -0,0 +1,148 @@
+use crate::{mock::*, CheckRateLimit, ERA_LIMIT_EXCEEDED};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, Hooks},
+	weights::{DispatchClass, DispatchInfo},
+};
+use sp_runtime::{
+	traits::{BadOrigin, SignedExtension},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+};
+
+const USER: u64 = 1;
+const OTHER: u64 = 2;
+
+fn remark() -> Call {
+	Call::System(frame_system::Call::remark { remark: vec![] })
+}
+
+/// Run the extension for a transaction from `who` as if it was included in the current block.
+fn include(who: u64) -> Result<(), TransactionValidityError> {
+	CheckRateLimit::<Test>::new().pre_dispatch(&who, &remark(), &DispatchInfo::default(), 10)
+}
+
+fn validate(who: u64) -> bool {
+	CheckRateLimit::<Test>::new()
+		.validate(&who, &remark(), &DispatchInfo::default(), 10)
+		.is_ok()
+}
+
+/// The error keeping a transaction in the pool for a later block.
+fn deferred() -> Result<(), TransactionValidityError> {
+	Err(InvalidTransaction::ExhaustsResources.into())
+}
+
+#[test]
+fn only_limit_origin_sets_limits() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(RateLimit::set_limits(Origin::signed(USER), 1, 1), BadOrigin);
+		assert_ok!(RateLimit::set_limits(Origin::root(), 2, 5));
+		assert_eq!(RateLimit::limits().per_block, 2);
+		assert_eq!(RateLimit::limits().per_era, 5);
+	});
+}
+
+#[test]
+fn no_limits_by_default() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..100 {
+			assert_ok!(include(USER));
+		}
+		assert!(!crate::Usages::<Test>::contains_key(0, USER));
+	});
+}
+
+#[test]
+fn per_block_limit_defers_transactions() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RateLimit::set_limits(Origin::root(), 2, 0));
+
+		assert_ok!(include(USER));
+		assert_ok!(include(USER));
+		// The transaction stays in the pool for a later block.
+		assert_eq!(include(USER), deferred());
+		assert!(validate(USER));
+		assert_ok!(include(OTHER));
+
+		System::set_block_number(2);
+		assert_ok!(include(USER));
+	});
+}
+
+#[test]
+fn per_era_limit_rejects_transactions() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RateLimit::set_limits(Origin::root(), 0, 2));
+
+		assert_ok!(include(USER));
+		System::set_block_number(2);
+		assert_ok!(include(USER));
+		System::set_block_number(3);
+		assert_eq!(
+			CheckRateLimit::<Test>::new().validate(&USER, &remark(), &DispatchInfo::default(), 10),
+			Err(InvalidTransaction::Custom(ERA_LIMIT_EXCEEDED).into())
+		);
+		// A transaction that was already in the pool is not dropped with its later nonces.
+		assert_eq!(include(USER), deferred());
+
+		// Blocks 10 to 19 form the next era.
+		System::set_block_number(10);
+		assert!(validate(USER));
+		assert_ok!(include(USER));
+		assert_eq!(RateLimit::usage(1, USER).in_era, 1);
+	});
+}
+
+#[test]
+fn operational_transactions_are_not_limited() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RateLimit::set_limits(Origin::root(), 1, 1));
+		let info = DispatchInfo { class: DispatchClass::Operational, ..Default::default() };
+		let extension = CheckRateLimit::<Test>::new();
+
+		assert_ok!(include(USER));
+		assert!(extension.validate(&USER, &remark(), &info, 10).is_ok());
+		assert_ok!(extension.pre_dispatch(&USER, &remark(), &info, 10));
+		assert_eq!(RateLimit::usage(0, USER).in_era, 1);
+	});
+}
+
+#[test]
+fn usage_of_past_eras_is_removed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RateLimit::set_limits(Origin::root(), 0, 5));
+		assert_ok!(include(USER));
+		assert_ok!(include(OTHER));
+
+		System::set_block_number(10);
+		assert_ok!(include(USER));
+		// Nothing is done without enough weight.
+		assert_eq!(RateLimit::on_idle(10, 0), 0);
+		assert!(crate::Usages::<Test>::contains_key(0, USER));
+
+		assert!(RateLimit::on_idle(10, u64::MAX) > 0);
+		assert!(!crate::Usages::<Test>::contains_key(0, USER));
+		assert!(!crate::Usages::<Test>::contains_key(0, OTHER));
+		assert_eq!(RateLimit::usage(1, USER).in_era, 1);
+		assert_eq!(crate::OldestEra::<Test>::get(), 1);
+	});
+}
+
+#[test]
+fn pruning_stays_within_the_idle_weight() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(RateLimit::set_limits(Origin::root(), 0, 5));
+		assert_ok!(include(USER));
+		System::set_block_number(50);
+
+		// Enough for the oldest era, one removal and one empty era.
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let weight = db.reads_writes(1, 1) + 2 * db.reads_writes(1, 1);
+		assert!(RateLimit::on_idle(50, weight) <= weight);
+		assert!(!crate::Usages::<Test>::contains_key(0, USER));
+		assert_eq!(crate::OldestEra::<Test>::get(), 2);
+
+		assert!(RateLimit::on_idle(50, u64::MAX) > 0);
+		assert_eq!(crate::OldestEra::<Test>::get(), 5);
+	});
+}
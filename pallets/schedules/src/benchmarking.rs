@@ -0,0 +1,85 @@
+//! Benchmarking setup for pallet-schedules
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::traits::Currency;
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, One, Saturating};
+
+const SEED: u32 = 0;
+
+/// The shortest period a schedule may use.
+fn min_period<T: Config>() -> T::BlockNumber {
+	T::MinPeriod::get().max(One::one())
+}
+
+/// Register schedule `0`, owned by the whitelisted caller and fed by the returned account, with
+/// submissions for its first `r` windows. Then move far enough ahead that all of them have
+/// expired and the benchmarked call records missed windows, and return the current window.
+fn setup_schedule<T: Config>(r: u32) -> Result<(T::AccountId, T::AccountId, u32), &'static str> {
+	let owner: T::AccountId = whitelisted_caller();
+	let feed: T::AccountId = account("feed", 0, SEED);
+	T::Currency::make_free_balance_be(&owner, BalanceOf::<T>::max_value());
+	Pallet::<T>::register_schedule(
+		RawOrigin::Signed(owner.clone()).into(),
+		feed.clone(),
+		min_period::<T>(),
+	)?;
+	for window in 0..r {
+		Submissions::<T>::insert(0, window, T::Hash::default());
+	}
+	Schedules::<T>::mutate(0, |maybe_info| {
+		if let Some(info) = maybe_info {
+			info.next_window = r;
+		}
+	});
+
+	let window = r.saturating_add(T::SubmissionRetention::get()).saturating_add(1);
+	let now = frame_system::Pallet::<T>::block_number();
+	frame_system::Pallet::<T>::set_block_number(
+		now.saturating_add(min_period::<T>().saturating_mul(window.into())),
+	);
+	Ok((owner, feed, window))
+}
+
+benchmarks! {
+	register_schedule {
+		let caller: T::AccountId = whitelisted_caller();
+		let feed: T::AccountId = account("feed", 0, SEED);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	}: _(RawOrigin::Signed(caller.clone()), feed, min_period::<T>())
+	verify {
+		assert_eq!(Schedules::<T>::get(0).map(|info| info.owner), Some(caller));
+	}
+
+	cancel_schedule {
+		let r in 0 .. T::SubmissionRetention::get();
+		let (owner, _, _) = setup_schedule::<T>(r)?;
+	}: _(RawOrigin::Signed(owner), 0)
+	verify {
+		assert!(!Schedules::<T>::contains_key(0));
+		assert_eq!(Submissions::<T>::iter_key_prefix(0).count(), 0);
+	}
+
+	submit {
+		let r in 0 .. T::SubmissionRetention::get();
+		let (_, feed, window) = setup_schedule::<T>(r)?;
+		let hash = T::Hash::default();
+	}: _(RawOrigin::Signed(feed), 0, hash)
+	verify {
+		assert_eq!(Submissions::<T>::iter_key_prefix(0).count(), 1);
+		assert_eq!(Submissions::<T>::get(0, window), Some(hash));
+	}
+
+	settle {
+		let r in 0 .. T::SubmissionRetention::get();
+		let (owner, _, window) = setup_schedule::<T>(r)?;
+	}: _(RawOrigin::Signed(owner), 0)
+	verify {
+		assert_eq!(Submissions::<T>::iter_key_prefix(0).count(), 0);
+		assert_eq!(Schedules::<T>::get(0).map(|info| info.missed), Some(window - r));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,334 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Recurring notarization schedules.
+///
+/// A schedule asks a designated feed account to anchor one hash per collection window of
+/// `period` blocks, which makes it a good fit for periodic log anchoring. Windows are derived
+/// from the block number, so no hook has to open or close them: a window is settled as missed
+/// as soon as the feed submits for a later window, when anyone calls `settle` after the window
+/// has passed, or when `on_idle` reaches the schedule. `on_idle` settles schedules in turn
+/// with the weight left over at the end of each block. Only the hashes of the last
+/// `SubmissionRetention` windows are kept in storage; older ones remain in the events.
+pub use pallet::*;
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Contains, Currency, ReservableCurrency},
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{Saturating, UniqueSaturatedInto, Zero};
+	use sp_std::prelude::*;
+
+	/// The identifier of a schedule.
+	pub type ScheduleId = u32;
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// A recurring request for a feed to anchor one hash per window.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct Schedule<AccountId, Balance, BlockNumber> {
+		/// The account that registered the schedule and holds its deposit.
+		pub owner: AccountId,
+		/// The account expected to submit a hash in every window.
+		pub feed: AccountId,
+		/// The amount reserved from the owner.
+		pub deposit: Balance,
+		/// The block at which the first window opens.
+		pub start: BlockNumber,
+		/// The length of a window, in blocks.
+		pub period: BlockNumber,
+		/// The first window that has been neither submitted to nor recorded as missed.
+		pub next_window: u32,
+		/// The number of windows that passed without a submission.
+		pub missed: u32,
+	}
+
+	pub type ScheduleOf<T> = Schedule<
+		<T as frame_system::Config>::AccountId,
+		BalanceOf<T>,
+		<T as frame_system::Config>::BlockNumber,
+	>;
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency used for schedule deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The amount reserved for every registered schedule.
+		#[pallet::constant]
+		type ScheduleDeposit: Get<BalanceOf<Self>>;
+
+		/// The accounts that register schedules without a deposit.
+		type DepositExempt: Contains<Self::AccountId>;
+
+		/// The shortest window a schedule may use.
+		#[pallet::constant]
+		type MinPeriod: Get<Self::BlockNumber>;
+
+		/// The number of most recent windows whose submitted hash is kept in storage.
+		#[pallet::constant]
+		type SubmissionRetention: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The identifier that will be given to the next schedule.
+	#[pallet::storage]
+	#[pallet::getter(fn next_schedule_id)]
+	pub type NextScheduleId<T> = StorageValue<_, ScheduleId, ValueQuery>;
+
+	/// The active schedules.
+	#[pallet::storage]
+	#[pallet::getter(fn schedules)]
+	pub type Schedules<T: Config> = StorageMap<_, Twox64Concat, ScheduleId, ScheduleOf<T>>;
+
+	/// The hash submitted for each of the last `SubmissionRetention` windows of a schedule.
+	#[pallet::storage]
+	#[pallet::getter(fn submissions)]
+	pub type Submissions<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, ScheduleId, Twox64Concat, u32, T::Hash>;
+
+	/// The last schedule `on_idle` settled. It continues with the next one in storage order.
+	#[pallet::storage]
+	pub type SettleCursor<T> = StorageValue<_, ScheduleId, ValueQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A schedule was registered. [schedule, owner, feed]
+		ScheduleRegistered(ScheduleId, T::AccountId, T::AccountId),
+		/// A schedule was cancelled by its owner. [schedule]
+		ScheduleCancelled(ScheduleId),
+		/// The feed anchored a hash for a window. [schedule, window, hash]
+		Submitted(ScheduleId, u32, T::Hash),
+		/// Windows passed without a submission. [schedule, first_window, count]
+		SubmissionsMissed(ScheduleId, u32, u32),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The schedule does not exist.
+		UnknownSchedule,
+		/// The period is shorter than `MinPeriod`.
+		PeriodTooShort,
+		/// Only the owner of the schedule may perform this action.
+		NotOwner,
+		/// Only the feed of the schedule may submit hashes.
+		NotFeed,
+		/// A hash was already submitted for the current window.
+		AlreadySubmitted,
+		/// No more schedule identifiers are available.
+		NoAvailableScheduleId,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			// Reading the cursor and the end of the map, and writing the cursor.
+			let mut used = T::DbWeight::get().reads_writes(2, 1);
+			if used > remaining_weight {
+				return 0
+			}
+
+			// Visit every existing schedule at most once per block, in storage order, from the
+			// one after the last settled schedule around to that schedule.
+			let last = Schedules::<T>::hashed_key_for(SettleCursor::<T>::get());
+			let wrapped = Schedules::<T>::iter()
+				.take_while(|(id, _)| Schedules::<T>::hashed_key_for(id) <= last);
+			let mut schedules = Schedules::<T>::iter_from(last.clone()).chain(wrapped);
+			let mut cursor = None;
+			loop {
+				let next = used.saturating_add(Self::settle_weight());
+				if next > remaining_weight {
+					break
+				}
+				let (id, mut info) = match schedules.next() {
+					Some(schedule) => schedule,
+					None => {
+						// Reading the key that ends the iteration.
+						used = used.saturating_add(T::DbWeight::get().reads(1));
+						break
+					},
+				};
+				used = next;
+
+				let window = Self::current_window(&info);
+				Self::record_missed(id, &mut info, window);
+				Self::prune_submissions(id, window);
+				Schedules::<T>::insert(id, info);
+				cursor = Some(id);
+			}
+			if let Some(cursor) = cursor {
+				SettleCursor::<T>::put(cursor);
+			}
+
+			used
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Register a schedule expecting `feed` to submit one hash every `period` blocks,
+		/// starting with the current block. `ScheduleDeposit` is reserved unless the caller is
+		/// `DepositExempt`.
+		#[pallet::weight(T::WeightInfo::register_schedule())]
+		pub fn register_schedule(
+			origin: OriginFor<T>,
+			feed: T::AccountId,
+			period: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(period >= T::MinPeriod::get() && !period.is_zero(), Error::<T>::PeriodTooShort);
+			let id = NextScheduleId::<T>::get();
+			let next = id.checked_add(1).ok_or(Error::<T>::NoAvailableScheduleId)?;
+
+			let deposit = if T::DepositExempt::contains(&who) {
+				Zero::zero()
+			} else {
+				T::ScheduleDeposit::get()
+			};
+			T::Currency::reserve(&who, deposit)?;
+
+			Schedules::<T>::insert(
+				id,
+				Schedule {
+					owner: who.clone(),
+					feed: feed.clone(),
+					deposit,
+					start: <frame_system::Pallet<T>>::block_number(),
+					period,
+					next_window: 0,
+					missed: 0,
+				},
+			);
+			NextScheduleId::<T>::put(next);
+
+			Self::deposit_event(Event::ScheduleRegistered(id, who, feed));
+			Ok(())
+		}
+
+		/// Cancel a schedule, remove its submissions and release its deposit.
+		#[pallet::weight(T::WeightInfo::cancel_schedule(T::SubmissionRetention::get()))]
+		pub fn cancel_schedule(origin: OriginFor<T>, schedule: ScheduleId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let info = Schedules::<T>::get(schedule).ok_or(Error::<T>::UnknownSchedule)?;
+			ensure!(info.owner == who, Error::<T>::NotOwner);
+
+			T::Currency::unreserve(&info.owner, info.deposit);
+			Submissions::<T>::remove_prefix(schedule, None);
+			Schedules::<T>::remove(schedule);
+
+			Self::deposit_event(Event::ScheduleCancelled(schedule));
+			Ok(())
+		}
+
+		/// Anchor `hash` for the current window of `schedule`.
+		///
+		/// The dispatch origin must be the feed of the schedule. Any earlier window that was
+		/// skipped is recorded as missed.
+		#[pallet::weight(T::WeightInfo::submit(T::SubmissionRetention::get()))]
+		pub fn submit(origin: OriginFor<T>, schedule: ScheduleId, hash: T::Hash) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let window = Schedules::<T>::try_mutate(schedule, |maybe_info| {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::UnknownSchedule)?;
+				ensure!(info.feed == who, Error::<T>::NotFeed);
+
+				let window = Self::current_window(info);
+				ensure!(window >= info.next_window, Error::<T>::AlreadySubmitted);
+				Self::record_missed(schedule, info, window);
+				info.next_window = window.saturating_add(1);
+				Ok::<_, Error<T>>(window)
+			})?;
+			Self::prune_submissions(schedule, window);
+			Submissions::<T>::insert(schedule, window, hash);
+
+			Self::deposit_event(Event::Submitted(schedule, window, hash));
+			Ok(())
+		}
+
+		/// Record every fully elapsed window of `schedule` without a submission as missed.
+		///
+		/// Anyone may call this to bring the statistics of an idle schedule up to date before
+		/// `on_idle` reaches it. Submissions older than `SubmissionRetention` windows are
+		/// removed.
+		#[pallet::weight(Pallet::<T>::settle_weight())]
+		pub fn settle(origin: OriginFor<T>, schedule: ScheduleId) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let window = Schedules::<T>::try_mutate(schedule, |maybe_info| {
+				let info = maybe_info.as_mut().ok_or(Error::<T>::UnknownSchedule)?;
+				let window = Self::current_window(info);
+				Self::record_missed(schedule, info, window);
+				Ok::<_, Error<T>>(window)
+			})?;
+			Self::prune_submissions(schedule, window);
+
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The index of the window containing the current block.
+		pub fn current_window(info: &ScheduleOf<T>) -> u32 {
+			let now = <frame_system::Pallet<T>>::block_number();
+			let elapsed = now.saturating_sub(info.start);
+			(elapsed / info.period).unique_saturated_into()
+		}
+
+		/// The weight of settling one schedule, including the removal of expired submissions.
+		pub fn settle_weight() -> Weight {
+			T::WeightInfo::settle(T::SubmissionRetention::get())
+		}
+
+		/// Remove the submissions of `schedule` that are `SubmissionRetention` or more windows
+		/// older than `window`. At most `SubmissionRetention` submissions are stored, so this
+		/// reads and removes at most that many.
+		fn prune_submissions(schedule: ScheduleId, window: u32) {
+			let retention = T::SubmissionRetention::get();
+			let expired: Vec<u32> = Submissions::<T>::iter_key_prefix(schedule)
+				.filter(|submitted| submitted.saturating_add(retention) <= window)
+				.collect();
+			for submitted in expired {
+				Submissions::<T>::remove(schedule, submitted);
+			}
+		}
+
+		/// Mark the windows between `info.next_window` and `window` (exclusive) as missed.
+		fn record_missed(schedule: ScheduleId, info: &mut ScheduleOf<T>, window: u32) {
+			if window > info.next_window {
+				let count = window - info.next_window;
+				Self::deposit_event(Event::SubmissionsMissed(schedule, info.next_window, count));
+				info.missed = info.missed.saturating_add(count);
+				info.next_window = window;
+			}
+		}
+	}
+}
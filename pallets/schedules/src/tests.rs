@@ -0,0 +1,157 @@
+use crate::{mock::*, Error, Event as SchedulesEvent};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{Get, Hooks},
+	weights::Weight,
+};
+use sp_core::H256;
+
+const OWNER: u64 = 1;
+const FEED: u64 = 2;
+
+fn run_to_block(n: u64) {
+	System::set_block_number(n);
+}
+
+#[test]
+fn register_schedule_reserves_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Schedules::register_schedule(Origin::signed(OWNER), FEED, 9),
+			Error::<Test>::PeriodTooShort
+		);
+		assert_ok!(Schedules::register_schedule(Origin::signed(OWNER), FEED, 10));
+		assert_eq!(Balances::reserved_balance(OWNER), 5);
+		assert_eq!(Schedules::schedules(0).unwrap().start, 1);
+
+		assert_noop!(Schedules::cancel_schedule(Origin::signed(FEED), 0), Error::<Test>::NotOwner);
+		assert_ok!(Schedules::cancel_schedule(Origin::signed(OWNER), 0));
+		assert_eq!(Balances::reserved_balance(OWNER), 0);
+		assert!(Schedules::schedules(0).is_none());
+	});
+}
+
+#[test]
+fn exempt_accounts_register_without_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Schedules::register_schedule(Origin::signed(EXEMPT), FEED, 10));
+		assert_eq!(Schedules::schedules(0).unwrap().deposit, 0);
+		assert_ok!(Schedules::cancel_schedule(Origin::signed(EXEMPT), 0));
+	});
+}
+
+#[test]
+fn one_submission_per_window() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Schedules::register_schedule(Origin::signed(OWNER), FEED, 10));
+
+		assert_noop!(
+			Schedules::submit(Origin::signed(OWNER), 0, H256::repeat_byte(1)),
+			Error::<Test>::NotFeed
+		);
+		assert_ok!(Schedules::submit(Origin::signed(FEED), 0, H256::repeat_byte(1)));
+		run_to_block(10);
+		assert_noop!(
+			Schedules::submit(Origin::signed(FEED), 0, H256::repeat_byte(2)),
+			Error::<Test>::AlreadySubmitted
+		);
+
+		run_to_block(11);
+		assert_ok!(Schedules::submit(Origin::signed(FEED), 0, H256::repeat_byte(2)));
+		assert_eq!(Schedules::submissions(0, 0), Some(H256::repeat_byte(1)));
+		assert_eq!(Schedules::submissions(0, 1), Some(H256::repeat_byte(2)));
+		assert_eq!(Schedules::schedules(0).unwrap().missed, 0);
+	});
+}
+
+#[test]
+fn skipped_windows_are_recorded_as_missed() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Schedules::register_schedule(Origin::signed(OWNER), FEED, 10));
+
+		// Windows 0 and 1 elapse without a submission.
+		run_to_block(21);
+		assert_ok!(Schedules::submit(Origin::signed(FEED), 0, H256::repeat_byte(1)));
+		System::assert_has_event(SchedulesEvent::SubmissionsMissed(0, 0, 2).into());
+		assert_eq!(Schedules::schedules(0).unwrap().missed, 2);
+
+		// Anyone can settle idle schedules; the open window is not settled yet.
+		run_to_block(45);
+		assert_ok!(Schedules::settle(Origin::signed(3), 0));
+		let info = Schedules::schedules(0).unwrap();
+		assert_eq!(info.missed, 3);
+		assert_eq!(info.next_window, 4);
+		assert_ok!(Schedules::submit(Origin::signed(FEED), 0, H256::repeat_byte(2)));
+		assert_eq!(Schedules::submissions(0, 4), Some(H256::repeat_byte(2)));
+	});
+}
+
+#[test]
+fn old_submissions_are_pruned() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Schedules::register_schedule(Origin::signed(OWNER), FEED, 10));
+		assert_ok!(Schedules::submit(Origin::signed(FEED), 0, H256::repeat_byte(1)));
+		run_to_block(11);
+		assert_ok!(Schedules::submit(Origin::signed(FEED), 0, H256::repeat_byte(2)));
+
+		// Only the last two windows are kept.
+		run_to_block(21);
+		assert_ok!(Schedules::submit(Origin::signed(FEED), 0, H256::repeat_byte(3)));
+		assert_eq!(Schedules::submissions(0, 0), None);
+		assert_eq!(Schedules::submissions(0, 1), Some(H256::repeat_byte(2)));
+
+		run_to_block(41);
+		assert_ok!(Schedules::settle(Origin::signed(3), 0));
+		assert_eq!(Schedules::submissions(0, 1), None);
+		assert_eq!(Schedules::submissions(0, 2), None);
+
+		assert_ok!(Schedules::submit(Origin::signed(FEED), 0, H256::repeat_byte(4)));
+		assert_ok!(Schedules::cancel_schedule(Origin::signed(OWNER), 0));
+		assert_eq!(Schedules::submissions(0, 4), None);
+	});
+}
+
+#[test]
+fn idle_weight_settles_schedules_in_turn() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Schedules::register_schedule(Origin::signed(OWNER), FEED, 10));
+		assert_ok!(Schedules::register_schedule(Origin::signed(OWNER), FEED, 10));
+		run_to_block(21);
+
+		// Enough weight for one schedule only.
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let one = db.reads_writes(2, 1) + Schedules::settle_weight();
+		let missed = || (0..2).filter(|id| Schedules::schedules(id).unwrap().missed == 2).count();
+		assert_eq!(Schedules::on_idle(21, one), one);
+		assert_eq!(missed(), 1);
+
+		Schedules::on_idle(21, one);
+		assert_eq!(missed(), 2);
+		// Without enough weight nothing is done.
+		assert_eq!(Schedules::on_idle(21, 0), 0);
+	});
+}
+
+#[test]
+fn cancelled_schedules_are_not_visited() {
+	new_test_ext().execute_with(|| {
+		for _ in 0..3 {
+			assert_ok!(Schedules::register_schedule(Origin::signed(OWNER), FEED, 10));
+		}
+		assert_ok!(Schedules::cancel_schedule(Origin::signed(OWNER), 0));
+		assert_ok!(Schedules::cancel_schedule(Origin::signed(OWNER), 2));
+		run_to_block(21);
+
+		// Only the remaining schedule is settled, plus the read ending the iteration.
+		let db = <Test as frame_system::Config>::DbWeight::get();
+		let expected = db.reads_writes(3, 1) + Schedules::settle_weight();
+		assert_eq!(Schedules::on_idle(21, Weight::max_value()), expected);
+		assert_eq!(Schedules::schedules(1).unwrap().missed, 2);
+		assert_eq!(crate::SettleCursor::<Test>::get(), 1);
+
+		// Every schedule is visited at most once per block.
+		run_to_block(31);
+		assert_eq!(Schedules::on_idle(31, Weight::max_value()), expected);
+		assert_eq!(Schedules::schedules(1).unwrap().missed, 3);
+	});
+}
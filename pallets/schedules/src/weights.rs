@@ -0,0 +1,107 @@
+//! Placeholder weights for pallet_schedules
+//!
+//! NOT GENERATED: these are hand-written estimates in the format of the benchmark CLI, not
+//! measurements. Replace this file with the output of `node-template benchmark-all` on reference
+//! hardware before a release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_schedules.
+pub trait WeightInfo {
+	fn register_schedule() -> Weight;
+	fn cancel_schedule(r: u32, ) -> Weight;
+	fn submit(r: u32, ) -> Weight;
+	fn settle(r: u32, ) -> Weight;
+}
+
+/// Placeholder weights for pallet_schedules, priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Schedules NextScheduleId (r:1 w:1)
+	// Storage: Whitelist Allowances (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Schedules Schedules (r:0 w:1)
+	fn register_schedule() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Schedules Schedules (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Schedules Submissions (r:0 w:1)
+	fn cancel_schedule(r: u32, ) -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add((1_100_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Schedules Schedules (r:1 w:1)
+	// Storage: Schedules Submissions (r:1 w:1)
+	fn submit(r: u32, ) -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add((2_300_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Schedules Schedules (r:1 w:1)
+	// Storage: Schedules Submissions (r:1 w:1)
+	fn settle(r: u32, ) -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add((2_300_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Schedules NextScheduleId (r:1 w:1)
+	// Storage: Whitelist Allowances (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Schedules Schedules (r:0 w:1)
+	fn register_schedule() -> Weight {
+		(40_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Schedules Schedules (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Schedules Submissions (r:0 w:1)
+	fn cancel_schedule(r: u32, ) -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add((1_100_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Schedules Schedules (r:1 w:1)
+	// Storage: Schedules Submissions (r:1 w:1)
+	fn submit(r: u32, ) -> Weight {
+		(33_000_000 as Weight)
+			.saturating_add((2_300_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: Schedules Schedules (r:1 w:1)
+	// Storage: Schedules Submissions (r:1 w:1)
+	fn settle(r: u32, ) -> Weight {
+		(27_000_000 as Weight)
+			.saturating_add((2_300_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(r as Weight)))
+	}
+}
@@ -0,0 +1,79 @@
+//! Benchmarking setup for pallet-sponsorship
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{sp_runtime::traits::Bounded, traits::Currency};
+use frame_system::RawOrigin;
+
+const SEED: u32 = 0;
+
+/// The whitelisted caller, holding enough funds to fill its pot.
+fn funded_sponsor<T: Config>() -> T::AccountId {
+	let sponsor: T::AccountId = whitelisted_caller();
+	fund_account::<T>(&sponsor);
+	sponsor
+}
+
+/// Give `who` enough funds to reserve deposits and fill its pot.
+fn fund_account<T: Config>(who: &T::AccountId) {
+	T::Currency::make_free_balance_be(who, BalanceOf::<T>::max_value() / 2u32.into());
+}
+
+benchmarks! {
+	fund {
+		let sponsor = funded_sponsor::<T>();
+		let amount = T::Currency::minimum_balance() * 10u32.into();
+	}: _(RawOrigin::Signed(sponsor.clone()), amount)
+	verify {
+		assert_eq!(T::Currency::free_balance(&Pallet::<T>::pot_account(&sponsor)), amount);
+	}
+
+	withdraw {
+		let sponsor = funded_sponsor::<T>();
+		let amount = T::Currency::minimum_balance() * 10u32.into();
+		Pallet::<T>::fund(RawOrigin::Signed(sponsor.clone()).into(), amount)?;
+	}: _(RawOrigin::Signed(sponsor.clone()), amount)
+	verify {
+		assert_eq!(T::Currency::free_balance(&Pallet::<T>::pot_account(&sponsor)), 0u32.into());
+	}
+
+	add_sponsee {
+		let sponsor = funded_sponsor::<T>();
+		let sponsee: T::AccountId = account("sponsee", 0, SEED);
+	}: _(RawOrigin::Signed(sponsor.clone()), sponsee.clone())
+	verify {
+		assert!(Offers::<T>::contains_key(&sponsee, &sponsor));
+	}
+
+	accept_sponsorship {
+		let sponsor: T::AccountId = account("sponsor", 0, SEED);
+		fund_account::<T>(&sponsor);
+		let sponsee: T::AccountId = whitelisted_caller();
+		Pallet::<T>::add_sponsee(RawOrigin::Signed(sponsor.clone()).into(), sponsee.clone())?;
+	}: _(RawOrigin::Signed(sponsee.clone()), sponsor.clone())
+	verify {
+		assert_eq!(Sponsors::<T>::get(&sponsee), Some(sponsor));
+	}
+
+	remove_sponsee {
+		let sponsor = funded_sponsor::<T>();
+		let sponsee: T::AccountId = account("sponsee", 0, SEED);
+		Pallet::<T>::add_sponsee(RawOrigin::Signed(sponsor.clone()).into(), sponsee.clone())?;
+		Pallet::<T>::accept_sponsorship(RawOrigin::Signed(sponsee.clone()).into(), sponsor.clone())?;
+	}: _(RawOrigin::Signed(sponsor), sponsee.clone())
+	verify {
+		assert!(!Sponsors::<T>::contains_key(&sponsee));
+	}
+
+	set_sponsored_calls {
+		let n in 0 .. T::MaxSponsoredCalls::get();
+		let sponsor: T::AccountId = whitelisted_caller();
+		let calls = (0 .. n).map(|i| [0, i as u8]).collect::<sp_std::vec::Vec<_>>();
+	}: _(RawOrigin::Signed(sponsor.clone()), calls)
+	verify {
+		assert_eq!(SponsoredCalls::<T>::get(&sponsor).len() as u32, n);
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
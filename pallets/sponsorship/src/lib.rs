@@ -0,0 +1,404 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Transaction fee sponsorship.
+///
+/// A sponsor funds a pot account derived from `PalletId` and offers to pay the fees of other
+/// accounts, which become its sponsees once they accept. Every pending offer reserves
+/// `OfferDeposit` from the sponsor until it is accepted or withdrawn. It can restrict
+/// sponsorship to a list of calls, each identified by its pallet and call index. The
+/// [`ChargeSponsored`] signed extension wraps the runtime's fee-charging extension and hands
+/// it the sponsor's pot instead of the sender whenever the call is sponsored, carries no tip
+/// and the pot can cover the fee, so businesses can pay the fees of their users. When the pot
+/// runs dry, sponsees pay their own fees again.
+pub use pallet::*;
+
+use codec::{Decode, Encode};
+use frame_support::weights::DispatchInfo;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{
+		DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SaturatedConversion, SignedExtension,
+		Zero,
+	},
+	transaction_validity::{TransactionValidity, TransactionValidityError},
+	DispatchResult,
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::WeightInfo;
+	use frame_support::{
+		pallet_prelude::*,
+		traits::{Currency, ExistenceRequirement, ReservableCurrency},
+		PalletId,
+	};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::{AccountIdConversion, Saturating};
+	use sp_std::prelude::*;
+
+	/// The pallet index and call index a call is encoded with.
+	pub type CallIndex = [u8; 2];
+
+	pub type BalanceOf<T> =
+		<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency fees are paid in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The amount reserved from a sponsor for every offer not accepted yet.
+		#[pallet::constant]
+		type OfferDeposit: Get<BalanceOf<Self>>;
+
+		/// The identifier the pot accounts of sponsors are derived from.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// The maximum number of calls a sponsor can restrict sponsorship to.
+		#[pallet::constant]
+		type MaxSponsoredCalls: Get<u32>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The sponsor paying the fees of each sponsored account.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsor_of)]
+	pub type Sponsors<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
+
+	/// The sponsorships offered to each account and not accepted yet, keyed by sponsee and
+	/// sponsor, with the deposit reserved from the sponsor.
+	#[pallet::storage]
+	#[pallet::getter(fn offers)]
+	pub type Offers<T: Config> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		T::AccountId,
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
+	/// The calls each sponsor pays for. An empty list covers every call.
+	#[pallet::storage]
+	#[pallet::getter(fn sponsored_calls)]
+	pub type SponsoredCalls<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		T::AccountId,
+		BoundedVec<CallIndex, T::MaxSponsoredCalls>,
+		ValueQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A sponsor added funds to its pot. [sponsor, amount]
+		PotFunded(T::AccountId, BalanceOf<T>),
+		/// A sponsor took funds out of its pot. [sponsor, amount]
+		PotWithdrawn(T::AccountId, BalanceOf<T>),
+		/// A sponsor offered to pay the fees of an account. [sponsor, who]
+		SponsorshipOffered(T::AccountId, T::AccountId),
+		/// A sponsor started paying the fees of an account. [sponsor, who]
+		SponseeAdded(T::AccountId, T::AccountId),
+		/// A sponsor stopped paying the fees of an account. [sponsor, who]
+		SponseeRemoved(T::AccountId, T::AccountId),
+		/// A sponsor changed the calls it pays for. [sponsor, calls]
+		SponsoredCallsSet(T::AccountId, u32),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is already sponsored.
+		AlreadySponsored,
+		/// The account is not sponsored.
+		NotSponsored,
+		/// The sponsor has not offered to pay the fees of the caller.
+		NotOffered,
+		/// The caller already offered to pay the fees of the account.
+		AlreadyOffered,
+		/// Only the sponsor or the sponsored account may end a sponsorship.
+		NotSponsor,
+		/// More calls than `MaxSponsoredCalls` were given.
+		TooManyCalls,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Move `amount` from the caller into its pot.
+		#[pallet::weight(T::WeightInfo::fund())]
+		pub fn fund(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			T::Currency::transfer(
+				&who,
+				&Self::pot_account(&who),
+				amount,
+				ExistenceRequirement::KeepAlive,
+			)?;
+
+			Self::deposit_event(Event::PotFunded(who, amount));
+			Ok(())
+		}
+
+		/// Move `amount` from the caller's pot back to the caller.
+		#[pallet::weight(T::WeightInfo::withdraw())]
+		pub fn withdraw(origin: OriginFor<T>, amount: BalanceOf<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			T::Currency::transfer(
+				&Self::pot_account(&who),
+				&who,
+				amount,
+				ExistenceRequirement::AllowDeath,
+			)?;
+
+			Self::deposit_event(Event::PotWithdrawn(who, amount));
+			Ok(())
+		}
+
+		/// Offer to pay the fees of `sponsee` from the caller's pot, reserving `OfferDeposit`.
+		/// The sponsorship starts once `sponsee` calls `accept_sponsorship`.
+		#[pallet::weight(T::WeightInfo::add_sponsee())]
+		pub fn add_sponsee(origin: OriginFor<T>, sponsee: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Offers::<T>::contains_key(&sponsee, &who), Error::<T>::AlreadyOffered);
+			let deposit = T::OfferDeposit::get();
+			T::Currency::reserve(&who, deposit)?;
+			Offers::<T>::insert(&sponsee, &who, deposit);
+
+			Self::deposit_event(Event::SponsorshipOffered(who, sponsee));
+			Ok(())
+		}
+
+		/// Accept the offer of `sponsor` to pay the fees of the caller, which returns the
+		/// deposit of the offer to `sponsor`.
+		#[pallet::weight(T::WeightInfo::accept_sponsorship())]
+		pub fn accept_sponsorship(origin: OriginFor<T>, sponsor: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			ensure!(!Sponsors::<T>::contains_key(&who), Error::<T>::AlreadySponsored);
+			let deposit = Offers::<T>::take(&who, &sponsor).ok_or(Error::<T>::NotOffered)?;
+			T::Currency::unreserve(&sponsor, deposit);
+			Sponsors::<T>::insert(&who, &sponsor);
+
+			Self::deposit_event(Event::SponseeAdded(sponsor, who));
+			Ok(())
+		}
+
+		/// End the sponsorship of `sponsee`. Either the sponsor or the sponsee may call this.
+		/// A sponsor may also call this to withdraw an offer `sponsee` has not accepted.
+		#[pallet::weight(T::WeightInfo::remove_sponsee())]
+		pub fn remove_sponsee(origin: OriginFor<T>, sponsee: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			if let Some(deposit) = Offers::<T>::take(&sponsee, &who) {
+				T::Currency::unreserve(&who, deposit);
+				return Ok(())
+			}
+			let sponsor = Sponsors::<T>::get(&sponsee).ok_or(Error::<T>::NotSponsored)?;
+			ensure!(who == sponsor || who == sponsee, Error::<T>::NotSponsor);
+			Sponsors::<T>::remove(&sponsee);
+
+			Self::deposit_event(Event::SponseeRemoved(sponsor, sponsee));
+			Ok(())
+		}
+
+		/// Only pay for `calls`, or for every call if `calls` is empty.
+		#[pallet::weight(T::WeightInfo::set_sponsored_calls(calls.len() as u32))]
+		pub fn set_sponsored_calls(origin: OriginFor<T>, calls: Vec<CallIndex>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+
+			let calls: BoundedVec<_, _> = calls.try_into().map_err(|_| Error::<T>::TooManyCalls)?;
+			let count = calls.len() as u32;
+			if calls.is_empty() {
+				SponsoredCalls::<T>::remove(&who);
+			} else {
+				SponsoredCalls::<T>::insert(&who, calls);
+			}
+
+			Self::deposit_event(Event::SponsoredCallsSet(who, count));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account holding the funds of `sponsor`.
+		pub fn pot_account(sponsor: &T::AccountId) -> T::AccountId {
+			T::PalletId::get().into_sub_account(sponsor)
+		}
+
+		/// The account paying `fee` when `who` sends `call`: the pot of its sponsor if the
+		/// sponsor pays for the call and the pot can cover the fee, `who` otherwise.
+		pub fn payer<Call: Encode>(
+			who: &T::AccountId,
+			call: &Call,
+			fee: BalanceOf<T>,
+		) -> T::AccountId {
+			let sponsor = match Sponsors::<T>::get(who) {
+				Some(sponsor) => sponsor,
+				None => return who.clone(),
+			};
+			let calls = SponsoredCalls::<T>::get(&sponsor);
+			let index = call.using_encoded(|encoded| encoded.get(..2).map(|i| [i[0], i[1]]));
+			let sponsored = calls.is_empty() || index.map_or(false, |index| calls.contains(&index));
+
+			let pot = Self::pot_account(&sponsor);
+			// Fees are withdrawn keeping the pot alive.
+			let affordable = T::Currency::free_balance(&pot) >=
+				fee.saturating_add(T::Currency::minimum_balance());
+
+			if sponsored && affordable {
+				pot
+			} else {
+				who.clone()
+			}
+		}
+	}
+}
+
+/// Charge the fee of sponsored transactions to the sponsor's pot through the wrapped
+/// extension `S`, and to the sender otherwise.
+///
+/// The extension encodes exactly like `S` and uses the same identifier, so wallets keep
+/// attaching tips as they always did. Only the fee moves to the sponsor; the sender still
+/// signs and uses its own nonce. The pot is only charged for transactions without a tip and
+/// only if it can cover their fee. Otherwise the sender pays the fee and the tip itself, so
+/// sponsees cannot spend the pot on priority or on tips to block authors.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeSponsored<T: Config, S>(S, sp_std::marker::PhantomData<T>);
+
+impl<T: Config, S> ChargeSponsored<T, S> {
+	/// Wrap `charge`, which performs the actual fee payment.
+	pub fn new(charge: S) -> Self {
+		Self(charge, Default::default())
+	}
+}
+
+impl<T: Config, S> From<S> for ChargeSponsored<T, S> {
+	fn from(charge: S) -> Self {
+		Self::new(charge)
+	}
+}
+
+impl<T: Config, S: sp_std::fmt::Debug> sp_std::fmt::Debug for ChargeSponsored<T, S> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeSponsored({:?})", self.0)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+/// A fee-charging signed extension that also charges a tip chosen by the sender.
+pub trait Tipped {
+	/// The tip the extension charges on top of the fee.
+	fn tip(&self) -> u128;
+}
+
+impl<T: pallet_transaction_payment::Config> Tipped
+	for pallet_transaction_payment::ChargeTransactionPayment<T>
+{
+	fn tip(&self) -> u128 {
+		// The tip is private to the extension, which encodes as nothing but the compact tip.
+		// The compact encoding does not depend on the width of the balance type.
+		codec::Compact::<u128>::decode(&mut &self.encode()[..]).map_or(u128::MAX, |tip| tip.0)
+	}
+}
+
+impl<T: Config, S: Tipped> ChargeSponsored<T, S>
+where
+	T: pallet_transaction_payment::Config,
+	T::Call: Encode + Dispatchable<Info = DispatchInfo>,
+{
+	/// The account paying for `call` sent by `who`, which is `who` whenever it attaches a tip.
+	fn payer(
+		&self,
+		who: &T::AccountId,
+		call: &T::Call,
+		info: &DispatchInfoOf<T::Call>,
+		len: usize,
+	) -> T::AccountId {
+		if self.0.tip() != 0 {
+			return who.clone()
+		}
+		let fee =
+			pallet_transaction_payment::Pallet::<T>::compute_fee(len as u32, info, Zero::zero());
+		Pallet::<T>::payer(who, call, fee.saturated_into::<u128>().saturated_into())
+	}
+}
+
+impl<T: Config, S> SignedExtension for ChargeSponsored<T, S>
+where
+	S: SignedExtension<AccountId = T::AccountId, Call = T::Call, AdditionalSigned = ()> + Tipped,
+	T: pallet_transaction_payment::Config + Send + Sync,
+	T::Call: Encode + Dispatchable<Info = DispatchInfo>,
+{
+	const IDENTIFIER: &'static str = S::IDENTIFIER;
+	type AccountId = T::AccountId;
+	type Call = S::Call;
+	type AdditionalSigned = ();
+	type Pre = S::Pre;
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		self.0.additional_signed()
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		self.0.validate(&self.payer(who, call, info, len), call, info, len)
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let payer = self.payer(who, call, info, len);
+		self.0.pre_dispatch(&payer, call, info, len)
+	}
+
+	fn post_dispatch(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		S::post_dispatch(pre, info, post_info, len, result)
+	}
+}
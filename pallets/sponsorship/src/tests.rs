@@ -0,0 +1,204 @@
+use crate::{mock::*, ChargeSponsored, Error};
+use codec::Encode;
+use frame_support::{assert_noop, assert_ok, weights::DispatchInfo};
+use pallet_transaction_payment::ChargeTransactionPayment;
+use sp_runtime::traits::SignedExtension;
+
+const SPONSOR: u64 = 1;
+const USER: u64 = 2;
+const OTHER: u64 = 3;
+
+fn remark() -> Call {
+	Call::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn transfer() -> Call {
+	Call::Balances(pallet_balances::Call::transfer { dest: OTHER, value: 1 })
+}
+
+fn index_of(call: &Call) -> [u8; 2] {
+	let encoded = call.encode();
+	[encoded[0], encoded[1]]
+}
+
+/// Run the extension for `call` sent by `who` and return the fees paid by `who` and by the pot
+/// of `SPONSOR`.
+fn fees_paid(who: u64, call: Call) -> (u64, u64) {
+	fees_paid_with_tip(who, call, 0)
+}
+
+/// Like `fees_paid`, with `tip` attached to the transaction.
+fn fees_paid_with_tip(who: u64, call: Call, tip: u64) -> (u64, u64) {
+	let pot = Sponsorship::pot_account(&SPONSOR);
+	let info = DispatchInfo { weight: 100, ..Default::default() };
+	let before = (Balances::free_balance(who), Balances::free_balance(pot));
+	let pre = ChargeSponsored::<Test, _>::from(ChargeTransactionPayment::<Test>::from(tip))
+		.pre_dispatch(&who, &call, &info, 10)
+		.unwrap();
+	assert_ok!(ChargeSponsored::<Test, ChargeTransactionPayment<Test>>::post_dispatch(
+		pre,
+		&info,
+		&Default::default(),
+		10,
+		&Ok(())
+	));
+	(before.0 - Balances::free_balance(who), before.1 - Balances::free_balance(pot))
+}
+
+/// Make `SPONSOR` pay the fees of `who`.
+fn sponsor(who: u64) {
+	assert_ok!(Sponsorship::add_sponsee(Origin::signed(SPONSOR), who));
+	assert_ok!(Sponsorship::accept_sponsorship(Origin::signed(who), SPONSOR));
+}
+
+#[test]
+fn pot_can_be_funded_and_withdrawn() {
+	new_test_ext().execute_with(|| {
+		let pot = Sponsorship::pot_account(&SPONSOR);
+		assert_ok!(Sponsorship::fund(Origin::signed(SPONSOR), 1_000));
+		assert_eq!(Balances::free_balance(pot), 1_000);
+		assert_ok!(Sponsorship::withdraw(Origin::signed(SPONSOR), 1_000));
+		assert_eq!(Balances::free_balance(pot), 0);
+	});
+}
+
+#[test]
+fn sponsorship_is_managed_by_sponsor_or_sponsee() {
+	new_test_ext().execute_with(|| {
+		sponsor(USER);
+		assert_noop!(
+			Sponsorship::remove_sponsee(Origin::signed(OTHER), USER),
+			Error::<Test>::NotSponsor
+		);
+		assert_ok!(Sponsorship::add_sponsee(Origin::signed(OTHER), USER));
+		assert_noop!(
+			Sponsorship::accept_sponsorship(Origin::signed(USER), OTHER),
+			Error::<Test>::AlreadySponsored
+		);
+		assert_ok!(Sponsorship::remove_sponsee(Origin::signed(USER), USER));
+		assert_noop!(
+			Sponsorship::remove_sponsee(Origin::signed(SPONSOR), USER),
+			Error::<Test>::NotSponsored
+		);
+		assert_noop!(
+			Sponsorship::set_sponsored_calls(Origin::signed(SPONSOR), vec![[0, 0]; 3]),
+			Error::<Test>::TooManyCalls
+		);
+	});
+}
+
+#[test]
+fn sponsor_pays_the_fees_of_sponsees() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Sponsorship::fund(Origin::signed(SPONSOR), 1_000_000));
+		sponsor(USER);
+
+		let (user, pot) = fees_paid(USER, remark());
+		assert_eq!(user, 0);
+		assert!(pot > 0);
+
+		let (other, pot) = fees_paid(OTHER, remark());
+		assert!(other > 0);
+		assert_eq!(pot, 0);
+	});
+}
+
+#[test]
+fn sponsorship_can_be_restricted_to_calls() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Sponsorship::fund(Origin::signed(SPONSOR), 1_000_000));
+		sponsor(USER);
+		assert_ok!(Sponsorship::set_sponsored_calls(
+			Origin::signed(SPONSOR),
+			vec![index_of(&remark())]
+		));
+
+		assert_eq!(fees_paid(USER, remark()).0, 0);
+		assert!(fees_paid(USER, transfer()).0 > 0);
+
+		assert_ok!(Sponsorship::set_sponsored_calls(Origin::signed(SPONSOR), vec![]));
+		assert_eq!(fees_paid(USER, transfer()).0, 0);
+	});
+}
+
+#[test]
+fn sponsorship_requires_the_consent_of_the_sponsee() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Sponsorship::add_sponsee(Origin::signed(SPONSOR), USER));
+		assert_eq!(Sponsorship::sponsor_of(USER), None);
+		assert_noop!(
+			Sponsorship::accept_sponsorship(Origin::signed(USER), OTHER),
+			Error::<Test>::NotOffered
+		);
+
+		// The sponsor can withdraw its offer before it is accepted.
+		assert_ok!(Sponsorship::remove_sponsee(Origin::signed(SPONSOR), USER));
+		assert_noop!(
+			Sponsorship::accept_sponsorship(Origin::signed(USER), SPONSOR),
+			Error::<Test>::NotOffered
+		);
+	});
+}
+
+#[test]
+fn sponsees_pay_their_own_fees_when_the_pot_is_empty() {
+	new_test_ext().execute_with(|| {
+		// Sponsoring every call from an empty pot does not stop the sponsee from transacting.
+		sponsor(USER);
+		let (user, pot) = fees_paid(USER, remark());
+		assert!(user > 0);
+		assert_eq!(pot, 0);
+
+		// Including the call ending the sponsorship.
+		let remove = Call::Sponsorship(crate::Call::remove_sponsee { sponsee: USER });
+		assert!(fees_paid(USER, remove).0 > 0);
+		assert_ok!(Sponsorship::remove_sponsee(Origin::signed(USER), USER));
+	});
+}
+
+#[test]
+fn senders_pay_for_tipped_transactions() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Sponsorship::fund(Origin::signed(SPONSOR), 1_000_000));
+		sponsor(USER);
+		let (fee, _) = fees_paid(OTHER, remark());
+
+		// The pot does not pay for priority, and the sender pays the fee along with the tip.
+		let (user, pot) = fees_paid_with_tip(USER, remark(), 1_000);
+		assert_eq!(user, fee + 1_000);
+		assert_eq!(pot, 0);
+
+		// Even if the pot could only cover the fee, the sender pays for itself.
+		let funds = Balances::free_balance(Sponsorship::pot_account(&SPONSOR));
+		assert_ok!(Sponsorship::withdraw(Origin::signed(SPONSOR), funds - fee - 1));
+		let (user, pot) = fees_paid_with_tip(USER, remark(), 1_000);
+		assert_eq!(user, fee + 1_000);
+		assert_eq!(pot, 0);
+		assert_eq!(fees_paid(USER, remark()), (0, fee));
+	});
+}
+
+#[test]
+fn offers_reserve_a_deposit() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Sponsorship::add_sponsee(Origin::signed(SPONSOR), USER));
+		assert_ok!(Sponsorship::add_sponsee(Origin::signed(SPONSOR), OTHER));
+		assert_eq!(Balances::reserved_balance(SPONSOR), 2 * OfferDeposit::get());
+		assert_noop!(
+			Sponsorship::add_sponsee(Origin::signed(SPONSOR), USER),
+			Error::<Test>::AlreadyOffered
+		);
+
+		// Accepting and withdrawing an offer both return its deposit.
+		assert_ok!(Sponsorship::accept_sponsorship(Origin::signed(USER), SPONSOR));
+		assert_eq!(Balances::reserved_balance(SPONSOR), OfferDeposit::get());
+		assert_ok!(Sponsorship::remove_sponsee(Origin::signed(SPONSOR), OTHER));
+		assert_eq!(Balances::reserved_balance(SPONSOR), 0);
+
+		// Offers cannot be made without the deposit.
+		assert_noop!(
+			Sponsorship::add_sponsee(Origin::signed(4), USER),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+	});
+}
@@ -0,0 +1,113 @@
+//! Placeholder weights for pallet_sponsorship
+//!
+//! NOT GENERATED: these are hand-written estimates in the format of the benchmark CLI, not
+//! measurements. Replace this file with the output of `node-template benchmark-all` on reference
+//! hardware before a release.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_sponsorship.
+pub trait WeightInfo {
+	fn fund() -> Weight;
+	fn withdraw() -> Weight;
+	fn add_sponsee() -> Weight;
+	fn accept_sponsorship() -> Weight;
+	fn remove_sponsee() -> Weight;
+	fn set_sponsored_calls(n: u32, ) -> Weight;
+}
+
+/// Placeholder weights for pallet_sponsorship, priced with the runtime's `DbWeight`.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: System Account (r:2 w:2)
+	fn fund() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: System Account (r:2 w:2)
+	fn withdraw() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Sponsorship Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn add_sponsee() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Sponsorship Sponsors (r:1 w:1)
+	// Storage: Sponsorship Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn accept_sponsorship() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Sponsorship Offers (r:1 w:0)
+	// Storage: Sponsorship Sponsors (r:1 w:1)
+	// Storage: Sponsorship Offers (r:0 w:1)
+	fn remove_sponsee() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Sponsorship SponsoredCalls (r:0 w:1)
+	fn set_sponsored_calls(n: u32, ) -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: System Account (r:2 w:2)
+	fn fund() -> Weight {
+		(52_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: System Account (r:2 w:2)
+	fn withdraw() -> Weight {
+		(50_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Sponsorship Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn add_sponsee() -> Weight {
+		(31_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Sponsorship Sponsors (r:1 w:1)
+	// Storage: Sponsorship Offers (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn accept_sponsorship() -> Weight {
+		(38_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: Sponsorship Offers (r:1 w:0)
+	// Storage: Sponsorship Sponsors (r:1 w:1)
+	// Storage: Sponsorship Offers (r:0 w:1)
+	fn remove_sponsee() -> Weight {
+		(26_000_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Sponsorship SponsoredCalls (r:0 w:1)
+	fn set_sponsored_calls(n: u32, ) -> Weight {
+		(19_000_000 as Weight)
+			.saturating_add((4_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+}
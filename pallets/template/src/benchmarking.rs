@@ -9,11 +9,18 @@ use frame_system::RawOrigin;
 
 benchmarks! {
 	do_something {
-		let s in 0 .. 100;
 		let caller: T::AccountId = whitelisted_caller();
-	}: _(RawOrigin::Signed(caller), s)
+	}: _(RawOrigin::Signed(caller), 42)
 	verify {
-		assert_eq!(Something::<T>::get(), Some(s));
+		assert_eq!(Something::<T>::get(), Some(42));
+	}
+
+	cause_error {
+		let caller: T::AccountId = whitelisted_caller();
+		Something::<T>::put(42);
+	}: _(RawOrigin::Signed(caller))
+	verify {
+		assert_eq!(Something::<T>::get(), Some(43));
 	}
 
 	impl_benchmark_test_suite!(Template, crate::mock::new_test_ext(), crate::mock::Test);
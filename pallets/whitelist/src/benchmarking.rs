@@ -0,0 +1,29 @@
+//! Benchmarking setup for pallet-whitelist
+
+use super::*;
+
+use frame_benchmarking::{account, benchmarks};
+use frame_support::traits::EnsureOrigin;
+
+const SEED: u32 = 0;
+
+benchmarks! {
+	whitelist_account {
+		let origin = T::WhitelistOrigin::successful_origin();
+		let who: T::AccountId = account("who", 0, SEED);
+	}: _<T::Origin>(origin, who.clone(), 10)
+	verify {
+		assert_eq!(Allowances::<T>::get(&who).map(|allowance| allowance.limit), Some(10));
+	}
+
+	remove_account {
+		let origin = T::WhitelistOrigin::successful_origin();
+		let who: T::AccountId = account("who", 0, SEED);
+		Pallet::<T>::whitelist_account(T::WhitelistOrigin::successful_origin(), who.clone(), 10)?;
+	}: _<T::Origin>(origin, who.clone())
+	verify {
+		assert!(!Allowances::<T>::contains_key(&who));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,278 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+/// Fee waivers for whitelisted public-good accounts.
+///
+/// `WhitelistOrigin` grants accounts an allowance of free transactions per `Period` blocks.
+/// The [`ChargeTransactionPaymentOrWaive`] signed extension wraps the runtime's fee-charging
+/// extension and skips fee payment while the sender has allowance left; once it is used up,
+/// the sender pays fees as usual until the next period. Pallets charging deposits consult the
+/// pallet through `Contains<AccountId>` to exempt the same accounts; the runtime wires it into
+/// the organization and schedule deposits. Stakes that can be slashed, such as notary bonds,
+/// and the deposits of `pallet-recovery` are not waived.
+pub use pallet::*;
+
+use codec::{Decode, Encode};
+use frame_support::weights::{DispatchInfo, PostDispatchInfo};
+use pallet_transaction_payment::ChargeTransactionPayment;
+use scale_info::TypeInfo;
+use sp_runtime::{
+	traits::{DispatchInfoOf, Dispatchable, PostDispatchInfoOf, SignedExtension},
+	transaction_validity::{TransactionValidity, TransactionValidityError},
+	DispatchResult,
+};
+
+#[cfg(test)]
+mod mock;
+
+#[cfg(test)]
+mod tests;
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+
+pub mod weights;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::WeightInfo;
+	use frame_support::{pallet_prelude::*, traits::Contains};
+	use frame_system::pallet_prelude::*;
+	use sp_runtime::traits::Zero;
+
+	/// The free transaction allowance of a whitelisted account.
+	#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+	pub struct Allowance<BlockNumber> {
+		/// The number of free transactions per period.
+		pub limit: u32,
+		/// The number of free transactions used in `period`.
+		pub used: u32,
+		/// The index of the period `used` refers to.
+		pub period: BlockNumber,
+	}
+
+	/// Configure the pallet by specifying the parameters and types on which it depends.
+	#[pallet::config]
+	pub trait Config: frame_system::Config + pallet_transaction_payment::Config {
+		/// Because this pallet emits events, it depends on the runtime's definition of an event.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin allowed to add accounts to and remove them from the whitelist.
+		type WhitelistOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The number of blocks after which allowances are replenished.
+		#[pallet::constant]
+		type Period: Get<Self::BlockNumber>;
+
+		/// Weight information for the extrinsics of this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	/// The whitelisted accounts and their allowances.
+	#[pallet::storage]
+	#[pallet::getter(fn allowances)]
+	pub type Allowances<T: Config> =
+		StorageMap<_, Blake2_128Concat, T::AccountId, Allowance<T::BlockNumber>>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An account was whitelisted or its allowance changed. [who, limit]
+		AccountWhitelisted(T::AccountId, u32),
+		/// An account was removed from the whitelist. [who]
+		AccountRemoved(T::AccountId),
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The account is not whitelisted.
+		NotWhitelisted,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Allow `who` to send `limit` free transactions per period.
+		///
+		/// Calling this for an already whitelisted account only changes its limit. The dispatch
+		/// origin must be `WhitelistOrigin`.
+		#[pallet::weight(T::WeightInfo::whitelist_account())]
+		pub fn whitelist_account(
+			origin: OriginFor<T>,
+			who: T::AccountId,
+			limit: u32,
+		) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+
+			let period = Self::current_period();
+			Allowances::<T>::mutate(&who, |maybe_allowance| match maybe_allowance {
+				Some(allowance) => allowance.limit = limit,
+				None => *maybe_allowance = Some(Allowance { limit, used: 0, period }),
+			});
+
+			Self::deposit_event(Event::AccountWhitelisted(who, limit));
+			Ok(())
+		}
+
+		/// Remove `who` from the whitelist. The dispatch origin must be `WhitelistOrigin`.
+		#[pallet::weight(T::WeightInfo::remove_account())]
+		pub fn remove_account(origin: OriginFor<T>, who: T::AccountId) -> DispatchResult {
+			T::WhitelistOrigin::ensure_origin(origin)?;
+
+			ensure!(Allowances::<T>::contains_key(&who), Error::<T>::NotWhitelisted);
+			Allowances::<T>::remove(&who);
+
+			Self::deposit_event(Event::AccountRemoved(who));
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The index of the current allowance period.
+		fn current_period() -> T::BlockNumber {
+			let period = T::Period::get();
+			if period.is_zero() {
+				Zero::zero()
+			} else {
+				<frame_system::Pallet<T>>::block_number() / period
+			}
+		}
+
+		/// Whether a transaction sent by `who` now would be free of charge.
+		pub fn can_waive(who: &T::AccountId) -> bool {
+			Allowances::<T>::get(who).map_or(false, |allowance| {
+				allowance.period != Self::current_period() || allowance.used < allowance.limit
+			})
+		}
+
+		/// Consume one free transaction from the allowance of `who`, returning whether there
+		/// was one left.
+		pub fn use_allowance(who: &T::AccountId) -> bool {
+			let period = Self::current_period();
+			Allowances::<T>::mutate(who, |maybe_allowance| match maybe_allowance {
+				Some(allowance) => {
+					if allowance.period != period {
+						allowance.period = period;
+						allowance.used = 0;
+					}
+					if allowance.used < allowance.limit {
+						allowance.used += 1;
+						true
+					} else {
+						false
+					}
+				},
+				None => false,
+			})
+		}
+	}
+
+	impl<T: Config> Contains<T::AccountId> for Pallet<T> {
+		fn contains(who: &T::AccountId) -> bool {
+			Allowances::<T>::contains_key(who)
+		}
+	}
+}
+
+/// Waive the transaction fee of whitelisted accounts that have allowance left, and charge it
+/// through the wrapped extension `S` otherwise.
+///
+/// The waiver is decided for the sender, so this extension must wrap any extension that
+/// changes who pays, such as a sponsorship extension, rather than be wrapped by it. It encodes
+/// exactly like `S` and uses the same identifier, so wallets keep attaching tips as they always
+/// did.
+///
+/// The pool cannot tell how many pending transactions an allowance will still cover, so
+/// transactions are validated by `S` as if they paid: a whitelisted sender must be able to
+/// afford the fee, and how many free transactions it may keep pending is bounded like for
+/// every other account. The allowance is only consumed, and the fee only waived, once the
+/// transaction is included.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct ChargeTransactionPaymentOrWaive<T: Config, S = ChargeTransactionPayment<T>>(
+	S,
+	sp_std::marker::PhantomData<T>,
+);
+
+impl<T: Config, S> ChargeTransactionPaymentOrWaive<T, S> {
+	/// Wrap `charge`, which is used for accounts without a free allowance.
+	pub fn new(charge: S) -> Self {
+		Self(charge, Default::default())
+	}
+}
+
+impl<T: Config, S> From<S> for ChargeTransactionPaymentOrWaive<T, S> {
+	fn from(charge: S) -> Self {
+		Self::new(charge)
+	}
+}
+
+impl<T: Config, S: sp_std::fmt::Debug> sp_std::fmt::Debug
+	for ChargeTransactionPaymentOrWaive<T, S>
+{
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "ChargeTransactionPaymentOrWaive({:?})", self.0)
+	}
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config, S> SignedExtension for ChargeTransactionPaymentOrWaive<T, S>
+where
+	S: SignedExtension<AccountId = T::AccountId, Call = T::Call, AdditionalSigned = ()>,
+	T::Call: Dispatchable<Info = DispatchInfo, PostInfo = PostDispatchInfo>,
+{
+	const IDENTIFIER: &'static str = S::IDENTIFIER;
+	type AccountId = T::AccountId;
+	type Call = T::Call;
+	type AdditionalSigned = ();
+	/// `None` when the fee was waived.
+	type Pre = Option<S::Pre>;
+
+	fn additional_signed(&self) -> Result<(), TransactionValidityError> {
+		self.0.additional_signed()
+	}
+
+	fn validate(
+		&self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> TransactionValidity {
+		self.0.validate(who, call, info, len)
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		if Pallet::<T>::use_allowance(who) {
+			Ok(None)
+		} else {
+			self.0.pre_dispatch(who, call, info, len).map(Some)
+		}
+	}
+
+	fn post_dispatch(
+		pre: Self::Pre,
+		info: &DispatchInfoOf<Self::Call>,
+		post_info: &PostDispatchInfoOf<Self::Call>,
+		len: usize,
+		result: &DispatchResult,
+	) -> Result<(), TransactionValidityError> {
+		match pre {
+			Some(pre) => S::post_dispatch(pre, info, post_info, len, result),
+			None => Ok(()),
+		}
+	}
+}
@@ -0,0 +1,92 @@
+use crate::{mock::*, ChargeTransactionPaymentOrWaive, Error};
+use frame_support::{assert_noop, assert_ok, weights::DispatchInfo};
+use pallet_transaction_payment::ChargeTransactionPayment;
+use sp_runtime::{
+	traits::{BadOrigin, SignedExtension},
+	transaction_validity::InvalidTransaction,
+};
+
+const REGISTRAR: u64 = 1;
+const USER: u64 = 2;
+
+fn remark() -> Call {
+	Call::System(frame_system::Call::remark { remark: vec![] })
+}
+
+/// Run the extension for a transaction from `who` and return the fee it paid.
+fn fee_paid_by(who: u64) -> u64 {
+	let info = DispatchInfo { weight: 100, ..Default::default() };
+	let before = Balances::free_balance(who);
+	let pre = ChargeTransactionPaymentOrWaive::<Test>::from(ChargeTransactionPayment::from(0))
+		.pre_dispatch(&who, &remark(), &info, 10)
+		.unwrap();
+	assert_ok!(ChargeTransactionPaymentOrWaive::<Test>::post_dispatch(
+		pre,
+		&info,
+		&Default::default(),
+		10,
+		&Ok(())
+	));
+	before - Balances::free_balance(who)
+}
+
+#[test]
+fn only_whitelist_origin_manages_accounts() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Whitelist::whitelist_account(Origin::signed(USER), USER, 5), BadOrigin);
+		assert_noop!(
+			Whitelist::remove_account(Origin::root(), REGISTRAR),
+			Error::<Test>::NotWhitelisted
+		);
+		assert_ok!(Whitelist::whitelist_account(Origin::root(), REGISTRAR, 5));
+		assert_eq!(Whitelist::allowances(REGISTRAR).unwrap().limit, 5);
+		assert_ok!(Whitelist::remove_account(Origin::root(), REGISTRAR));
+		assert!(Whitelist::allowances(REGISTRAR).is_none());
+	});
+}
+
+#[test]
+fn whitelisted_accounts_do_not_pay_fees() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Whitelist::whitelist_account(Origin::root(), REGISTRAR, 2));
+
+		assert_eq!(fee_paid_by(REGISTRAR), 0);
+		assert!(fee_paid_by(USER) > 0);
+	});
+}
+
+#[test]
+fn allowance_is_rate_limited_per_period() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Whitelist::whitelist_account(Origin::root(), REGISTRAR, 2));
+
+		assert_eq!(fee_paid_by(REGISTRAR), 0);
+		assert_eq!(fee_paid_by(REGISTRAR), 0);
+		assert!(!Whitelist::can_waive(&REGISTRAR));
+		assert!(fee_paid_by(REGISTRAR) > 0);
+
+		// The allowance is replenished in the next period.
+		System::set_block_number(10);
+		assert!(Whitelist::can_waive(&REGISTRAR));
+		assert_eq!(fee_paid_by(REGISTRAR), 0);
+		assert_eq!(Whitelist::allowances(REGISTRAR).unwrap().used, 1);
+	});
+}
+
+#[test]
+fn pending_transactions_are_validated_as_paying() {
+	new_test_ext().execute_with(|| {
+		const UNFUNDED: u64 = 3;
+		assert_ok!(Whitelist::whitelist_account(Origin::root(), REGISTRAR, 2));
+		assert_ok!(Whitelist::whitelist_account(Origin::root(), UNFUNDED, 2));
+
+		let info = DispatchInfo { weight: 100, ..Default::default() };
+		let validate = |who| {
+			ChargeTransactionPaymentOrWaive::<Test>::from(ChargeTransactionPayment::from(0))
+				.validate(&who, &remark(), &info, 10)
+		};
+		// Whitelisted transactions are prioritized like any other, by their tip.
+		assert_eq!(validate(REGISTRAR), validate(USER));
+		assert_eq!(validate(UNFUNDED), Err(InvalidTransaction::Payment.into()));
+	});
+}
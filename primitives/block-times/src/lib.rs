@@ -0,0 +1,18 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A runtime API mapping past blocks to wall-clock times.
+//!
+//! Certificates and explorers call [`BlockTimeApi::time_at`] to quote when an entry was
+//! registered. The runtime answers from the checkpoints kept by `pallet-block-times`, so the
+//! time is approximate between checkpoints and unknown for blocks older than the retention.
+//! Clients format the returned milliseconds as ISO 8601 themselves.
+
+use codec::Codec;
+
+sp_api::decl_runtime_apis! {
+	/// Report the time of past blocks.
+	pub trait BlockTimeApi<BlockNumber, Moment> where BlockNumber: Codec, Moment: Codec {
+		/// The approximate time of block `n`, or `None` if the runtime no longer knows it.
+		fn time_at(n: BlockNumber) -> Option<Moment>;
+	}
+}
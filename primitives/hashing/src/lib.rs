@@ -0,0 +1,170 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! Host functions hashing payloads streamed to the runtime in chunks.
+//!
+//! `sp_io::hashing` hashes natively, but only a single contiguous buffer, so content arriving
+//! as many chunks would first have to be concatenated in Wasm memory. The functions here keep
+//! the state of SHA2-256 and Keccak-256 hashers on the host between calls instead: the runtime
+//! starts a hasher, feeds it every chunk as it goes and finally reads the hash. Each chunk is
+//! passed by pointer, so the cost grows with the payload size at native speed and no buffer
+//! holding the whole payload is ever built.
+//!
+//! The hashers live in the [`HashersExt`] externalities extension, which is registered on
+//! first use and dropped at the end of the runtime call. A hasher therefore has to be finished
+//! in the runtime call that started it, and its identifier must never be stored: identifiers
+//! are only deterministic as long as every hasher is finished.
+//!
+//! The node registers [`HostFunctions`] with its executor. A runtime calling these functions
+//! can only be executed by nodes that do the same.
+
+use sp_runtime_interface::runtime_interface;
+
+#[cfg(feature = "std")]
+use sp_externalities::{Externalities, ExternalitiesExt};
+#[cfg(feature = "std")]
+use std::collections::BTreeMap;
+
+/// A hasher started by the runtime.
+#[cfg(feature = "std")]
+enum Hasher {
+	Sha2_256(sha2::Sha256),
+	Keccak256(tiny_keccak::Keccak),
+}
+
+#[cfg(feature = "std")]
+impl Hasher {
+	fn update(&mut self, data: &[u8]) {
+		match self {
+			Hasher::Sha2_256(hasher) => sha2::Digest::update(hasher, data),
+			Hasher::Keccak256(hasher) => tiny_keccak::Hasher::update(hasher, data),
+		}
+	}
+
+	fn finish(self) -> [u8; 32] {
+		match self {
+			Hasher::Sha2_256(hasher) => sha2::Digest::finalize(hasher).into(),
+			Hasher::Keccak256(hasher) => {
+				let mut output = [0u8; 32];
+				tiny_keccak::Hasher::finalize(hasher, &mut output);
+				output
+			},
+		}
+	}
+}
+
+/// The hashers started and not finished yet, by identifier.
+#[cfg(feature = "std")]
+#[derive(Default)]
+pub struct Hashers(BTreeMap<u32, Hasher>);
+
+#[cfg(feature = "std")]
+impl Hashers {
+	/// Keep track of `hasher` under the lowest identifier not in use.
+	fn start(&mut self, hasher: Hasher) -> u32 {
+		let id = (0..).find(|id| !self.0.contains_key(id)).expect("fewer than 2^32 hashers; qed");
+		self.0.insert(id, hasher);
+		id
+	}
+
+	fn update(&mut self, id: u32, data: &[u8]) -> bool {
+		self.0.get_mut(&id).map(|hasher| hasher.update(data)).is_some()
+	}
+
+	fn finish(&mut self, id: u32) -> Option<[u8; 32]> {
+		self.0.remove(&id).map(Hasher::finish)
+	}
+}
+
+#[cfg(feature = "std")]
+sp_externalities::decl_extension! {
+	/// The hashers the runtime started in the current runtime call.
+	pub struct HashersExt(Hashers);
+}
+
+/// The hashers of the current runtime call, registering the extension if it is the first use.
+#[cfg(feature = "std")]
+fn hashers(ext: &mut dyn Externalities) -> &mut Hashers {
+	if ext.extension::<HashersExt>().is_none() {
+		ext.register_extension(HashersExt(Hashers::default()))
+			.expect("the extension is not registered yet; qed");
+	}
+	&mut ext
+		.extension::<HashersExt>()
+		.expect("the extension was registered above; qed")
+		.0
+}
+
+/// Hash content supplied in chunks on the host.
+#[runtime_interface]
+pub trait ChunkedHashing {
+	/// Start a SHA2-256 hasher and return its identifier.
+	fn sha2_256_start(&mut self) -> u32 {
+		hashers(&mut **self).start(Hasher::Sha2_256(Default::default()))
+	}
+
+	/// Start a Keccak-256 hasher and return its identifier.
+	fn keccak_256_start(&mut self) -> u32 {
+		hashers(&mut **self).start(Hasher::Keccak256(tiny_keccak::Keccak::v256()))
+	}
+
+	/// Feed `chunk` to the hasher `id`. Returns `false` if no such hasher was started.
+	fn update(&mut self, id: u32, chunk: &[u8]) -> bool {
+		hashers(&mut **self).update(id, chunk)
+	}
+
+	/// The hash of everything fed to the hasher `id`, which is dropped. Returns `None` if no
+	/// such hasher was started.
+	fn finish(&mut self, id: u32) -> Option<[u8; 32]> {
+		hashers(&mut **self).finish(id)
+	}
+}
+
+/// The host functions the node has to provide for runtimes using this crate.
+#[cfg(feature = "std")]
+pub type HostFunctions = (chunked_hashing::HostFunctions,);
+
+#[cfg(test)]
+mod tests {
+	use super::chunked_hashing;
+
+	fn chunks() -> Vec<Vec<u8>> {
+		vec![b"Proof".to_vec(), Vec::new(), b" of ".to_vec(), vec![7; 1 << 20]]
+	}
+
+	#[test]
+	fn chunked_hashes_match_contiguous_hashes() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			let sha2 = chunked_hashing::sha2_256_start();
+			let keccak = chunked_hashing::keccak_256_start();
+			for chunk in chunks() {
+				assert!(chunked_hashing::update(sha2, &chunk));
+				assert!(chunked_hashing::update(keccak, &chunk));
+			}
+
+			let contiguous = chunks().concat();
+			assert_eq!(
+				chunked_hashing::finish(sha2),
+				Some(sp_core::hashing::sha2_256(&contiguous))
+			);
+			assert_eq!(
+				chunked_hashing::finish(keccak),
+				Some(sp_core::hashing::keccak_256(&contiguous))
+			);
+		});
+	}
+
+	#[test]
+	fn finished_hashers_are_dropped() {
+		sp_io::TestExternalities::default().execute_with(|| {
+			assert_eq!(chunked_hashing::sha2_256_start(), 0);
+			assert_eq!(chunked_hashing::sha2_256_start(), 1);
+			assert_eq!(chunked_hashing::finish(0), Some(sp_core::hashing::sha2_256(&[])));
+
+			assert!(!chunked_hashing::update(0, b"chunk"));
+			assert_eq!(chunked_hashing::finish(0), None);
+			assert_eq!(chunked_hashing::finish(2), None);
+			// Identifiers of finished hashers are reused.
+			assert_eq!(chunked_hashing::keccak_256_start(), 0);
+		});
+	}
+}
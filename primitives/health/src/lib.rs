@@ -0,0 +1,32 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+//! A runtime API for network-level tests.
+//!
+//! Test scenarios running against real networks, such as the zombienet definitions in
+//! `zombienet/`, call [`HealthApi::health`] on every node to check that the nodes agree on the
+//! state of the chain and that the runtime keeps producing and finalizing blocks.
+
+use codec::{Codec, Decode, Encode};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+
+/// The state of the chain as seen by the runtime.
+#[derive(Encode, Decode, Clone, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub struct Health<BlockNumber> {
+	/// The number of the block the state belongs to.
+	pub block_number: BlockNumber,
+	/// The number of Aura authorities.
+	pub authorities: u32,
+	/// The identifier of the current GRANDPA authority set.
+	pub grandpa_set_id: u64,
+	/// The version of the runtime.
+	pub spec_version: u32,
+}
+
+sp_api::decl_runtime_apis! {
+	/// Report the health of the chain.
+	pub trait HealthApi<BlockNumber> where BlockNumber: Codec {
+		/// The state of the chain at the queried block.
+		fn health() -> Health<BlockNumber>;
+	}
+}
@@ -16,7 +16,7 @@ use sp_runtime::{
 	create_runtime_str, generic, impl_opaque_keys,
 	traits::{AccountIdLookup, BlakeTwo256, Block as BlockT, IdentifyAccount, NumberFor, Verify},
 	transaction_validity::{TransactionSource, TransactionValidity},
-	ApplyExtrinsicResult, MultiSignature,
+	ApplyExtrinsicResult, ConsensusEngineId, MultiSignature,
 };
 use sp_std::prelude::*;
 #[cfg(feature = "std")]
@@ -26,12 +26,15 @@ use sp_version::RuntimeVersion;
 // A few exports that help ease life for downstream crates.
 pub use frame_support::{
 	construct_runtime, parameter_types,
-	traits::{KeyOwnerProofSystem, Randomness, StorageInfo},
+	traits::{
+		Contains, Currency, FindAuthor, Imbalance, KeyOwnerProofSystem, OnUnbalanced, Randomness,
+		StorageInfo,
+	},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
 		IdentityFee, Weight,
 	},
-	StorageValue,
+	PalletId, StorageValue,
 };
 pub use pallet_balances::Call as BalancesCall;
 pub use pallet_timestamp::Call as TimestampCall;
@@ -98,10 +101,13 @@ pub const VERSION: RuntimeVersion = RuntimeVersion {
 	//   `spec_version`, and `authoring_version` are the same between Wasm and native.
 	// This value is set to 100 to notify Polkadot-JS App (https://polkadot.js.org/apps) to use
 	//   the compatible custom types.
-	spec_version: 100,
+	// Bumped with every change to the runtime logic.
+	spec_version: 120,
 	impl_version: 1,
 	apis: RUNTIME_API_VERSIONS,
-	transaction_version: 1,
+	// Bumped with every change to `SignedExtra` or to the encoding of existing calls, which
+	//   invalidates transactions signed for the previous version.
+	transaction_version: 5,
 };
 
 /// This determines the average expected block time that we are targeting.
@@ -140,11 +146,27 @@ parameter_types! {
 	pub const SS58Prefix: u8 = 42;
 }
 
+/// The basic call filter of the runtime.
+///
+/// Rejects recovery configurations whose delay period is shorter than `MinRecoveryDelay`, so that
+/// owners always have time to notice and close a malicious recovery attempt.
+pub struct BaseFilter;
+
+impl Contains<Call> for BaseFilter {
+	fn contains(call: &Call) -> bool {
+		match call {
+			Call::Recovery(pallet_recovery::Call::create_recovery { delay_period, .. }) =>
+				*delay_period >= MinRecoveryDelay::get(),
+			_ => true,
+		}
+	}
+}
+
 // Configure FRAME pallets to include in runtime.
 
 impl frame_system::Config for Runtime {
 	/// The basic call filter to use in dispatchable.
-	type BaseCallFilter = frame_support::traits::Everything;
+	type BaseCallFilter = BaseFilter;
 	/// Block & extrinsics weights: base values and limits.
 	type BlockWeights = BlockWeights;
 	/// The maximum length of a block (in bytes).
@@ -186,7 +208,7 @@ impl frame_system::Config for Runtime {
 	/// The data to be stored in an account.
 	type AccountData = pallet_balances::AccountData<Balance>;
 	/// Weight information for the extrinsics of this pallet.
-	type SystemWeightInfo = ();
+	type SystemWeightInfo = frame_system::weights::SubstrateWeight<Runtime>;
 	/// This is used as an identifier of the chain. 42 is the generic substrate prefix.
 	type SS58Prefix = SS58Prefix;
 	/// The set code logic, just the default since we're not a parachain.
@@ -234,7 +256,7 @@ impl pallet_timestamp::Config for Runtime {
 	type Moment = u64;
 	type OnTimestampSet = Aura;
 	type MinimumPeriod = MinimumPeriod;
-	type WeightInfo = ();
+	type WeightInfo = pallet_timestamp::weights::SubstrateWeight<Runtime>;
 }
 
 parameter_types! {
@@ -256,27 +278,341 @@ impl pallet_balances::Config for Runtime {
 	type WeightInfo = pallet_balances::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const UncleGenerations: BlockNumber = 0;
+}
+
+/// Resolves the block author from the Aura pre-runtime digest. Aura keys are sr25519 keys, like
+/// account keys, so the author is paid to the account of its Aura key.
+pub struct AuraAccountAdapter;
+
+impl FindAuthor<AccountId> for AuraAccountAdapter {
+	fn find_author<'a, I>(digests: I) -> Option<AccountId>
+	where
+		I: 'a + IntoIterator<Item = (ConsensusEngineId, &'a [u8])>,
+	{
+		pallet_aura::AuraAuthorId::<Runtime>::find_author(digests)
+			.map(|author| sp_core::sr25519::Public::from(author).into())
+	}
+}
+
+impl pallet_authorship::Config for Runtime {
+	type FindAuthor = AuraAccountAdapter;
+	type UncleGenerations = UncleGenerations;
+	type FilterUncle = ();
+	type EventHandler = ();
+}
+
+parameter_types! {
+	pub const ProposalBond: Permill = Permill::from_percent(5);
+	pub const ProposalBondMinimum: Balance = 10_000;
+	pub const SpendPeriod: BlockNumber = DAYS;
+	pub const Burn: Permill = Permill::from_percent(0);
+	pub const TreasuryPalletId: PalletId = PalletId(*b"py/trsry");
+	pub const MaxApprovals: u32 = 100;
+}
+
+impl pallet_treasury::Config for Runtime {
+	type PalletId = TreasuryPalletId;
+	type Currency = Balances;
+	type ApproveOrigin = frame_system::EnsureRoot<AccountId>;
+	type RejectOrigin = frame_system::EnsureRoot<AccountId>;
+	type Event = Event;
+	type OnSlash = Treasury;
+	type ProposalBond = ProposalBond;
+	type ProposalBondMinimum = ProposalBondMinimum;
+	type ProposalBondMaximum = ();
+	type SpendPeriod = SpendPeriod;
+	type Burn = Burn;
+	type BurnDestination = ();
+	type SpendFunds = ();
+	type WeightInfo = pallet_treasury::weights::SubstrateWeight<Runtime>;
+	type MaxApprovals = MaxApprovals;
+}
+
+type NegativeImbalance = <Balances as Currency<AccountId>>::NegativeImbalance;
+
+/// Pays the author of the current block.
+pub struct ToAuthor;
+
+impl OnUnbalanced<NegativeImbalance> for ToAuthor {
+	fn on_nonzero_unbalanced(amount: NegativeImbalance) {
+		Balances::resolve_creating(&Authorship::author(), amount);
+	}
+}
+
+/// Splits transaction fees 80% to the treasury and 20% to the block author, and pays tips to
+/// the block author in full.
+pub struct DealWithFees;
+
+impl OnUnbalanced<NegativeImbalance> for DealWithFees {
+	fn on_unbalanceds<B>(mut fees_then_tips: impl Iterator<Item = NegativeImbalance>) {
+		if let Some(fees) = fees_then_tips.next() {
+			let (to_treasury, mut to_author) = fees.ration(80, 20);
+			if let Some(tips) = fees_then_tips.next() {
+				tips.merge_into(&mut to_author);
+			}
+			Treasury::on_unbalanced(to_treasury);
+			ToAuthor::on_unbalanced(to_author);
+		}
+	}
+}
+
 parameter_types! {
 	pub const TransactionByteFee: Balance = 1;
 	pub OperationalFeeMultiplier: u8 = 5;
 }
 
 impl pallet_transaction_payment::Config for Runtime {
-	type OnChargeTransaction = CurrencyAdapter<Balances, ()>;
+	type OnChargeTransaction = CurrencyAdapter<Balances, DealWithFees>;
 	type TransactionByteFee = TransactionByteFee;
 	type OperationalFeeMultiplier = OperationalFeeMultiplier;
 	type WeightToFee = IdentityFee<Balance>;
 	type FeeMultiplierUpdate = ();
 }
 
+parameter_types! {
+	/// The base amount reserved for making an account recoverable.
+	pub const ConfigDepositBase: Balance = 10_000;
+	/// The amount reserved per friend of a recoverable account.
+	pub const FriendDepositFactor: Balance = 1_000;
+	pub const MaxFriends: u16 = 9;
+	/// The amount reserved for starting the recovery of an account.
+	pub const RecoveryDeposit: Balance = 10_000;
+	/// The shortest delay an account may configure between a recovery being initiated and its
+	/// friends being able to take over the account.
+	pub const MinRecoveryDelay: BlockNumber = DAYS;
+}
+
+impl pallet_recovery::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type Currency = Balances;
+	type ConfigDepositBase = ConfigDepositBase;
+	type FriendDepositFactor = FriendDepositFactor;
+	type MaxFriends = MaxFriends;
+	type RecoveryDeposit = RecoveryDeposit;
+}
+
 impl pallet_sudo::Config for Runtime {
 	type Event = Event;
 	type Call = Call;
 }
 
+impl pallet_utility::Config for Runtime {
+	type Event = Event;
+	type Call = Call;
+	type PalletsOrigin = OriginCaller;
+	type WeightInfo = pallet_utility::weights::SubstrateWeight<Runtime>;
+}
+
 /// Configure the pallet-template in pallets/template.
 impl pallet_template::Config for Runtime {
 	type Event = Event;
+	type WeightInfo = pallet_template::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxIssuerMetadataLen: u32 = 256;
+	pub const MaxStatusListBytes: u32 = 16 * 1024;
+}
+
+impl pallet_credentials::Config for Runtime {
+	type Event = Event;
+	type RegistrarOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxIssuerMetadataLen = MaxIssuerMetadataLen;
+	type MaxStatusListBytes = MaxStatusListBytes;
+	type WeightInfo = pallet_credentials::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const OrganizationDeposit: Balance = 50_000;
+	pub const MaxOrganizationNameLen: u32 = 64;
+	pub const MaxOrganizationMembers: u32 = 256;
+	pub const MaxOrganizationRoles: u32 = 32;
+}
+
+impl pallet_organizations::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type OrganizationDeposit = OrganizationDeposit;
+	type DepositExempt = Whitelist;
+	type MaxNameLen = MaxOrganizationNameLen;
+	type MaxMembers = MaxOrganizationMembers;
+	type MaxRoles = MaxOrganizationRoles;
+	type WeightInfo = pallet_organizations::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const ScheduleDeposit: Balance = 10_000;
+	pub const MinSchedulePeriod: BlockNumber = 10;
+	pub const ScheduleSubmissionRetention: u32 = 64;
+}
+
+impl pallet_schedules::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type ScheduleDeposit = ScheduleDeposit;
+	type DepositExempt = Whitelist;
+	type MinPeriod = MinSchedulePeriod;
+	type SubmissionRetention = ScheduleSubmissionRetention;
+	type WeightInfo = pallet_schedules::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const WhitelistPeriod: BlockNumber = DAYS;
+}
+
+impl pallet_whitelist::Config for Runtime {
+	type Event = Event;
+	type WhitelistOrigin = frame_system::EnsureRoot<AccountId>;
+	type Period = WhitelistPeriod;
+	type WeightInfo = pallet_whitelist::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub Prefix: &'static [u8] = b"Pay node-template tokens to the account:";
+	pub Statement: &'static [u8] =
+		b"I hereby agree to the terms of the node-template genesis distribution.";
+}
+
+impl pallet_claims::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type MintOrigin = frame_system::EnsureRoot<AccountId>;
+	type Prefix = Prefix;
+	type Statement = Statement;
+	type WeightInfo = pallet_claims::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MinimumNotaryStake: Balance = 100_000;
+	pub MinimumNotaryStakeKey: &'static [u8] = b"MinimumNotaryStake";
+	pub const NotaryUnbondingPeriod: BlockNumber = 7 * DAYS;
+	pub NotaryUnbondingPeriodKey: &'static [u8] = b"NotaryUnbondingPeriod";
+	pub const MaxNotaryProfileLen: u32 = 256;
+	pub const NotaryRatingDeposit: Balance = 1_000;
+	pub const MaxNotaryRatings: u32 = 1_000;
+}
+
+impl pallet_notaries::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type SlashOrigin = frame_system::EnsureRoot<AccountId>;
+	type Slash = Treasury;
+	type MinimumStake =
+		pallet_parameters::ParameterOr<Runtime, MinimumNotaryStakeKey, MinimumNotaryStake>;
+	type UnbondingPeriod =
+		pallet_parameters::ParameterOr<Runtime, NotaryUnbondingPeriodKey, NotaryUnbondingPeriod>;
+	type MaxProfileLen = MaxNotaryProfileLen;
+	type RatingDeposit = NotaryRatingDeposit;
+	type MaxRatings = MaxNotaryRatings;
+	type WeightInfo = pallet_notaries::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const SponsorshipPalletId: PalletId = PalletId(*b"py/spnsr");
+	pub const MaxSponsoredCalls: u32 = 16;
+	pub const SponsorshipOfferDeposit: Balance = 10_000;
+}
+
+impl pallet_sponsorship::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type PalletId = SponsorshipPalletId;
+	type OfferDeposit = SponsorshipOfferDeposit;
+	type MaxSponsoredCalls = MaxSponsoredCalls;
+	type WeightInfo = pallet_sponsorship::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const RateLimitEraLength: BlockNumber = HOURS;
+}
+
+impl pallet_rate_limit::Config for Runtime {
+	type Event = Event;
+	type LimitOrigin = frame_system::EnsureRoot<AccountId>;
+	type EraLength = RateLimitEraLength;
+	type WeightInfo = pallet_rate_limit::weights::SubstrateWeight<Runtime>;
+}
+
+/// Reports the accounts concerned by the events of the local pallets to the event index.
+pub struct IndexedEventAccounts;
+
+impl pallet_event_index::EventAccounts<Event, AccountId> for IndexedEventAccounts {
+	fn accounts(event: &Event) -> Vec<AccountId> {
+		use pallet_credentials::Event as CredentialsEvent;
+		use pallet_notaries::Event as NotariesEvent;
+		use pallet_organizations::Event as OrganizationsEvent;
+
+		match event {
+			Event::TemplateModule(pallet_template::Event::SomethingStored(_, who)) |
+			Event::Claims(pallet_claims::Event::Claimed(who, ..)) |
+			Event::Credentials(CredentialsEvent::IssuerAdded(who)) |
+			Event::Credentials(CredentialsEvent::CredentialIssued(who, ..)) |
+			Event::Credentials(CredentialsEvent::CredentialRevoked(who, _)) |
+			Event::Credentials(CredentialsEvent::CredentialForceRevoked(who, _)) |
+			Event::Organizations(OrganizationsEvent::OrganizationCreated(_, who)) |
+			Event::Organizations(OrganizationsEvent::OwnershipOffered(_, who)) |
+			Event::Organizations(OrganizationsEvent::OwnershipTransferred(_, who)) |
+			Event::Organizations(OrganizationsEvent::OrganizationDissolved(_, who)) |
+			Event::Organizations(OrganizationsEvent::MemberSet(_, who, _)) |
+			Event::Organizations(OrganizationsEvent::MemberRemoved(_, who)) |
+			Event::Notaries(NotariesEvent::NotaryRegistered(who, _)) |
+			Event::Notaries(NotariesEvent::NotarySlashed(who, _)) => vec![who.clone()],
+			Event::Notaries(NotariesEvent::NotaryRated(notary, client, _)) =>
+				vec![notary.clone(), client.clone()],
+			_ => Vec::new(),
+		}
+	}
+}
+
+parameter_types! {
+	pub const BloomRetention: BlockNumber = DAYS;
+	pub const MaxIndexedEvents: u32 = 1024;
+}
+
+impl pallet_event_index::Config for Runtime {
+	type EventAccounts = IndexedEventAccounts;
+	type BloomRetention = BloomRetention;
+	type MaxIndexedEvents = MaxIndexedEvents;
+	type WeightInfo = pallet_event_index::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxRecentIdempotencyKeys: u32 = 32;
+}
+
+impl pallet_idempotency::Config for Runtime {
+	type MaxRecentKeys = MaxRecentIdempotencyKeys;
+	type WeightInfo = pallet_idempotency::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MaxParameterKeyLen: u32 = 32;
+}
+
+impl pallet_parameters::Config for Runtime {
+	type Event = Event;
+	type ParameterOrigin = frame_system::EnsureRoot<AccountId>;
+	type MaxKeyLen = MaxParameterKeyLen;
+	type WeightInfo = pallet_parameters::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const CheckpointInterval: BlockNumber = 10 * MINUTES;
+	pub const MaxCheckpoints: u32 = 365 * DAYS / (10 * MINUTES);
+}
+
+impl pallet_block_times::Config for Runtime {
+	type Time = Timestamp;
+	type CheckpointInterval = CheckpointInterval;
+	type MaxCheckpoints = MaxCheckpoints;
+	type WeightInfo = pallet_block_times::weights::SubstrateWeight<Runtime>;
+}
+
+impl pallet_block_metrics::Config for Runtime {
+	type WeightInfo = pallet_block_metrics::weights::SubstrateWeight<Runtime>;
 }
 
 // Create the runtime by composing the FRAME pallets that were previously configured.
@@ -296,6 +632,23 @@ construct_runtime!(
 		Sudo: pallet_sudo,
 		// Include the custom logic from the pallet-template in the runtime.
 		TemplateModule: pallet_template,
+		Credentials: pallet_credentials,
+		Organizations: pallet_organizations,
+		Schedules: pallet_schedules,
+		Recovery: pallet_recovery,
+		Whitelist: pallet_whitelist,
+		Claims: pallet_claims,
+		Authorship: pallet_authorship,
+		Treasury: pallet_treasury,
+		Notaries: pallet_notaries,
+		Sponsorship: pallet_sponsorship,
+		RateLimit: pallet_rate_limit,
+		EventIndex: pallet_event_index,
+		Idempotency: pallet_idempotency,
+		Parameters: pallet_parameters,
+		BlockTimes: pallet_block_times,
+		BlockMetrics: pallet_block_metrics,
+		Utility: pallet_utility,
 	}
 );
 
@@ -313,7 +666,15 @@ pub type SignedExtra = (
 	frame_system::CheckEra<Runtime>,
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
-	pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+	pallet_rate_limit::CheckRateLimit<Runtime>,
+	pallet_idempotency::CheckIdempotencyKey<Runtime>,
+	pallet_whitelist::ChargeTransactionPaymentOrWaive<
+		Runtime,
+		pallet_sponsorship::ChargeSponsored<
+			Runtime,
+			pallet_transaction_payment::ChargeTransactionPayment<Runtime>,
+		>,
+	>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -457,6 +818,23 @@ impl_runtime_apis! {
 		}
 	}
 
+	impl node_template_health_api::HealthApi<Block, BlockNumber> for Runtime {
+		fn health() -> node_template_health_api::Health<BlockNumber> {
+			node_template_health_api::Health {
+				block_number: System::block_number(),
+				authorities: Aura::authorities().len() as u32,
+				grandpa_set_id: Grandpa::current_set_id(),
+				spec_version: VERSION.spec_version,
+			}
+		}
+	}
+
+	impl node_template_block_time_api::BlockTimeApi<Block, BlockNumber, u64> for Runtime {
+		fn time_at(n: BlockNumber) -> Option<u64> {
+			BlockTimes::time_at(n)
+		}
+	}
+
 	#[cfg(feature = "runtime-benchmarks")]
 	impl frame_benchmarking::Benchmark<Block> for Runtime {
 		fn benchmark_metadata(extra: bool) -> (
@@ -474,7 +852,22 @@ impl_runtime_apis! {
 			list_benchmark!(list, extra, frame_system, SystemBench::<Runtime>);
 			list_benchmark!(list, extra, pallet_balances, Balances);
 			list_benchmark!(list, extra, pallet_timestamp, Timestamp);
+			list_benchmark!(list, extra, pallet_treasury, Treasury);
 			list_benchmark!(list, extra, pallet_template, TemplateModule);
+			list_benchmark!(list, extra, pallet_credentials, Credentials);
+			list_benchmark!(list, extra, pallet_notaries, Notaries);
+			list_benchmark!(list, extra, pallet_organizations, Organizations);
+			list_benchmark!(list, extra, pallet_parameters, Parameters);
+			list_benchmark!(list, extra, pallet_rate_limit, RateLimit);
+			list_benchmark!(list, extra, pallet_schedules, Schedules);
+			list_benchmark!(list, extra, pallet_sponsorship, Sponsorship);
+			list_benchmark!(list, extra, pallet_whitelist, Whitelist);
+			list_benchmark!(list, extra, pallet_claims, Claims);
+			list_benchmark!(list, extra, pallet_event_index, EventIndex);
+			list_benchmark!(list, extra, pallet_idempotency, Idempotency);
+			list_benchmark!(list, extra, pallet_block_times, BlockTimes);
+			list_benchmark!(list, extra, pallet_block_metrics, BlockMetrics);
+			list_benchmark!(list, extra, pallet_utility, Utility);
 
 			let storage_info = AllPalletsWithSystem::storage_info();
 
@@ -512,9 +905,39 @@ impl_runtime_apis! {
 			add_benchmark!(params, batches, frame_system, SystemBench::<Runtime>);
 			add_benchmark!(params, batches, pallet_balances, Balances);
 			add_benchmark!(params, batches, pallet_timestamp, Timestamp);
+			add_benchmark!(params, batches, pallet_treasury, Treasury);
 			add_benchmark!(params, batches, pallet_template, TemplateModule);
+			add_benchmark!(params, batches, pallet_credentials, Credentials);
+			add_benchmark!(params, batches, pallet_notaries, Notaries);
+			add_benchmark!(params, batches, pallet_organizations, Organizations);
+			add_benchmark!(params, batches, pallet_parameters, Parameters);
+			add_benchmark!(params, batches, pallet_rate_limit, RateLimit);
+			add_benchmark!(params, batches, pallet_schedules, Schedules);
+			add_benchmark!(params, batches, pallet_sponsorship, Sponsorship);
+			add_benchmark!(params, batches, pallet_whitelist, Whitelist);
+			add_benchmark!(params, batches, pallet_claims, Claims);
+			add_benchmark!(params, batches, pallet_event_index, EventIndex);
+			add_benchmark!(params, batches, pallet_idempotency, Idempotency);
+			add_benchmark!(params, batches, pallet_block_times, BlockTimes);
+			add_benchmark!(params, batches, pallet_block_metrics, BlockMetrics);
+			add_benchmark!(params, batches, pallet_utility, Utility);
 
 			Ok(batches)
 		}
 	}
+
+	#[cfg(feature = "try-runtime")]
+	impl frame_try_runtime::TryRuntime<Block> for Runtime {
+		fn on_runtime_upgrade() -> (Weight, Weight) {
+			// NOTE: intentional unwrap: we don't want to propagate the error backwards, and want to
+			// have a backtrace here. If any of the pre/post migration checks fail, we shall stop
+			// right here and right now.
+			let weight = Executive::try_runtime_upgrade().unwrap();
+			(weight, BlockWeights::get().max_block)
+		}
+
+		fn execute_block_no_check(block: Block) -> Weight {
+			Executive::execute_block_no_check(block)
+		}
+	}
 }
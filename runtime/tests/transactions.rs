@@ -0,0 +1,316 @@
+//! Signed transactions applied to the production runtime configuration.
+
+use codec::Encode;
+use frame_support::weights::GetDispatchInfo;
+use node_template_runtime::{
+	AccountId, AuraConfig, Balance, Balances, BalancesConfig, Block, Call, Credentials, Executive,
+	GenesisConfig, Hash, Header, Index, Runtime, SignedExtra, Sponsorship, SudoConfig, Treasury,
+	UncheckedExtrinsic, Whitelist,
+};
+use sp_consensus_aura::AURA_ENGINE_ID;
+use sp_keyring::Sr25519Keyring::{self, Alice, Bob, Charlie};
+use sp_runtime::{
+	generic::{DigestItem, Era, SignedPayload},
+	traits::{BlakeTwo256, Block as _, Hash as _},
+	transaction_validity::{InvalidTransaction, TransactionValidityError},
+	ApplyExtrinsicResult, BuildStorage, DispatchError,
+};
+
+const ENDOWMENT: Balance = 1 << 60;
+
+/// The dev chain state, with Alice as sudo key, and block 1 initialized.
+fn new_test_ext() -> sp_io::TestExternalities {
+	new_test_ext_with_slot(None)
+}
+
+/// The dev chain state, with Alice as sudo key, and block 1 initialized with the Aura digest of
+/// `slot`, if any.
+fn new_test_ext_with_slot(slot: Option<u64>) -> sp_io::TestExternalities {
+	let mut ext = genesis();
+	ext.execute_with(|| {
+		let parent_hash = frame_system::Pallet::<Runtime>::block_hash(0);
+		let mut header =
+			Header::new(1, Default::default(), Default::default(), parent_hash, Default::default());
+		if let Some(slot) = slot {
+			// A `Slot` encodes like the `u64` it wraps.
+			header.digest_mut().push(DigestItem::PreRuntime(AURA_ENGINE_ID, slot.encode()));
+		}
+		Executive::initialize_block(&header);
+	});
+	ext
+}
+
+/// The dev chain state, with Alice as sudo key and Bob as the only Aura authority.
+fn genesis() -> sp_io::TestExternalities {
+	let storage = GenesisConfig {
+		aura: AuraConfig { authorities: vec![Bob.public().into()] },
+		balances: BalancesConfig {
+			balances: vec![(Alice.to_account_id(), ENDOWMENT), (Bob.to_account_id(), ENDOWMENT)],
+		},
+		sudo: SudoConfig { key: Alice.to_account_id() },
+		..Default::default()
+	}
+	.build_storage()
+	.unwrap();
+
+	sp_io::TestExternalities::new(storage)
+}
+
+/// Sign `call` as `signer` with the extensions wallets attach by default.
+fn sign(signer: Sr25519Keyring, nonce: Index, call: Call) -> UncheckedExtrinsic {
+	sign_with_tip(signer, nonce, call, 0)
+}
+
+/// Sign `call` as `signer`, tipping the block author `tip`.
+fn sign_with_tip(
+	signer: Sr25519Keyring,
+	nonce: Index,
+	call: Call,
+	tip: Balance,
+) -> UncheckedExtrinsic {
+	let extra: SignedExtra = (
+		frame_system::CheckSpecVersion::new(),
+		frame_system::CheckTxVersion::new(),
+		frame_system::CheckGenesis::new(),
+		frame_system::CheckEra::from(Era::Immortal),
+		frame_system::CheckNonce::from(nonce),
+		frame_system::CheckWeight::new(),
+		pallet_rate_limit::CheckRateLimit::new(),
+		pallet_idempotency::CheckIdempotencyKey::new(None),
+		pallet_whitelist::ChargeTransactionPaymentOrWaive::from(
+			pallet_sponsorship::ChargeSponsored::from(
+				pallet_transaction_payment::ChargeTransactionPayment::from(tip),
+			),
+		),
+	);
+	let payload = SignedPayload::new(call, extra).unwrap();
+	let signature = payload.using_encoded(|payload| signer.sign(payload));
+	let (call, extra, _) = payload.deconstruct();
+	UncheckedExtrinsic::new_signed(call, signer.to_account_id().into(), signature.into(), extra)
+}
+
+fn transfer(dest: AccountId, value: Balance) -> Call {
+	Call::Balances(pallet_balances::Call::transfer { dest: dest.into(), value })
+}
+
+fn apply(xt: UncheckedExtrinsic) -> ApplyExtrinsicResult {
+	Executive::apply_extrinsic(xt)
+}
+
+fn sudo(call: Call) -> Call {
+	Call::Sudo(pallet_sudo::Call::sudo { call: Box::new(call) })
+}
+
+fn batch_all(calls: Vec<Call>) -> Call {
+	Call::Utility(pallet_utility::Call::batch_all { calls })
+}
+
+/// The calls approving `issuer` and setting up a schema and a status list for it.
+fn issuer_setup(issuer: AccountId, schema: Hash) -> (Call, Call) {
+	let add = Call::Credentials(pallet_credentials::Call::add_issuer { issuer, metadata: vec![] });
+	let setup = batch_all(vec![
+		Call::Credentials(pallet_credentials::Call::register_schema { schema }),
+		Call::Credentials(pallet_credentials::Call::create_status_list {
+			list_id: 0,
+			schema,
+			capacity: 8,
+		}),
+	]);
+	(sudo(add), setup)
+}
+
+fn issue(credential: Hash) -> Call {
+	Call::Credentials(pallet_credentials::Call::issue_credential { credential, list_id: 0 })
+}
+
+#[test]
+fn signed_transfers_are_charged_fees() {
+	const TIP: Balance = 1_000_000;
+
+	// Bob, the only authority, authors block 1.
+	new_test_ext_with_slot(Some(1)).execute_with(|| {
+		let treasury = Balances::free_balance(Treasury::account_id());
+		let call = transfer(Charlie.to_account_id(), 1_000);
+		let weight = call.get_dispatch_info().weight;
+
+		assert_eq!(apply(sign_with_tip(Alice, 0, call, TIP)), Ok(Ok(())));
+
+		let fee = ENDOWMENT - 1_000 - TIP - Balances::free_balance(Alice.to_account_id());
+		// The weight fee and the length fee are charged.
+		assert!(fee > weight as Balance);
+		assert_eq!(Balances::free_balance(Charlie.to_account_id()), 1_000);
+		// 80% of the fee goes to the treasury, the rest and the whole tip to the author.
+		let to_treasury = fee * 80 / 100;
+		assert_eq!(Balances::free_balance(Treasury::account_id()), treasury + to_treasury);
+		assert_eq!(
+			Balances::free_balance(Bob.to_account_id()),
+			ENDOWMENT + fee - to_treasury + TIP
+		);
+	});
+}
+
+#[test]
+fn invalid_transactions_are_rejected() {
+	new_test_ext().execute_with(|| {
+		let call = transfer(Charlie.to_account_id(), 1_000);
+		assert_eq!(apply(sign(Alice, 0, call.clone())), Ok(Ok(())));
+
+		assert_eq!(
+			apply(sign(Alice, 0, call)),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Stale))
+		);
+		// Charlie cannot afford the fee.
+		assert_eq!(
+			apply(sign(Charlie, 0, transfer(Bob.to_account_id(), 1))),
+			Err(TransactionValidityError::Invalid(InvalidTransaction::Payment))
+		);
+	});
+}
+
+#[test]
+fn sudo_whitelisted_accounts_pay_no_fees() {
+	new_test_ext().execute_with(|| {
+		let whitelist = Call::Whitelist(pallet_whitelist::Call::whitelist_account {
+			who: Bob.to_account_id(),
+			limit: 1,
+		});
+		assert_eq!(apply(sign(Alice, 0, sudo(whitelist))), Ok(Ok(())));
+		assert!(Whitelist::can_waive(&Bob.to_account_id()));
+
+		assert_eq!(apply(sign(Bob, 0, transfer(Charlie.to_account_id(), 1_000))), Ok(Ok(())));
+		assert_eq!(Balances::free_balance(Bob.to_account_id()), ENDOWMENT - 1_000);
+
+		// The allowance is used up for this period.
+		assert_eq!(apply(sign(Bob, 1, transfer(Charlie.to_account_id(), 1_000))), Ok(Ok(())));
+		assert!(Balances::free_balance(Bob.to_account_id()) < ENDOWMENT - 2_000);
+	});
+}
+
+#[test]
+fn whitelisted_senders_pay_no_fees_when_sponsored() {
+	new_test_ext().execute_with(|| {
+		let pot = Sponsorship::pot_account(&Alice.to_account_id());
+		let fund = Call::Sponsorship(pallet_sponsorship::Call::fund { amount: ENDOWMENT / 2 });
+		let offer = Call::Sponsorship(pallet_sponsorship::Call::add_sponsee {
+			sponsee: Bob.to_account_id(),
+		});
+		let accept = Call::Sponsorship(pallet_sponsorship::Call::accept_sponsorship {
+			sponsor: Alice.to_account_id(),
+		});
+		assert_eq!(apply(sign(Alice, 0, fund)), Ok(Ok(())));
+		assert_eq!(apply(sign(Alice, 1, offer)), Ok(Ok(())));
+		assert_eq!(apply(sign(Bob, 0, accept)), Ok(Ok(())));
+
+		let whitelist = Call::Whitelist(pallet_whitelist::Call::whitelist_account {
+			who: Bob.to_account_id(),
+			limit: 1,
+		});
+		assert_eq!(apply(sign(Alice, 2, sudo(whitelist))), Ok(Ok(())));
+
+		// The allowance of the sender is used before the sponsor is charged.
+		let balance = Balances::free_balance(Bob.to_account_id());
+		let funds = Balances::free_balance(&pot);
+		assert_eq!(apply(sign(Bob, 1, transfer(Charlie.to_account_id(), 1_000))), Ok(Ok(())));
+		assert_eq!(Balances::free_balance(Bob.to_account_id()), balance - 1_000);
+		assert_eq!(Balances::free_balance(&pot), funds);
+
+		assert_eq!(apply(sign(Bob, 2, transfer(Charlie.to_account_id(), 1_000))), Ok(Ok(())));
+		assert_eq!(Balances::free_balance(Bob.to_account_id()), balance - 2_000);
+		assert!(Balances::free_balance(&pot) < funds);
+	});
+}
+
+#[test]
+fn batched_registry_calls_apply_atomically() {
+	new_test_ext().execute_with(|| {
+		let schema = BlakeTwo256::hash(b"schema");
+		let (add, setup) = issuer_setup(Bob.to_account_id(), schema);
+		assert_eq!(apply(sign(Alice, 0, add)), Ok(Ok(())));
+		assert_eq!(apply(sign(Bob, 0, setup)), Ok(Ok(())));
+
+		let first = BlakeTwo256::hash(b"first");
+		let second = BlakeTwo256::hash(b"second");
+		let batch = batch_all(vec![issue(first), issue(second)]);
+		assert_eq!(apply(sign(Bob, 1, batch)), Ok(Ok(())));
+		assert_eq!(Credentials::is_revoked(&first), Some(false));
+		assert_eq!(Credentials::is_revoked(&second), Some(false));
+
+		// Reissuing `first` fails the batch, so `third` is not issued either.
+		let third = BlakeTwo256::hash(b"third");
+		let batch = batch_all(vec![issue(third), issue(first)]);
+		assert!(matches!(apply(sign(Bob, 2, batch)), Ok(Err(_))));
+		assert_eq!(Credentials::is_revoked(&third), None);
+	});
+}
+
+#[test]
+fn governance_revokes_issuers() {
+	new_test_ext().execute_with(|| {
+		let schema = BlakeTwo256::hash(b"schema");
+		let credential = BlakeTwo256::hash(b"credential");
+		let (add, setup) = issuer_setup(Bob.to_account_id(), schema);
+		assert_eq!(apply(sign(Alice, 0, add)), Ok(Ok(())));
+		assert_eq!(apply(sign(Bob, 0, setup)), Ok(Ok(())));
+		assert_eq!(apply(sign(Bob, 1, issue(credential))), Ok(Ok(())));
+
+		let remove = Call::Credentials(pallet_credentials::Call::remove_issuer {
+			issuer: Bob.to_account_id(),
+		});
+		// Only the registrar origin, here sudo, revokes issuing rights.
+		assert_eq!(apply(sign(Bob, 2, remove.clone())), Ok(Err(DispatchError::BadOrigin)));
+		assert_eq!(apply(sign(Alice, 1, sudo(remove))), Ok(Ok(())));
+		assert!(!pallet_credentials::Issuers::<Runtime>::contains_key(Bob.to_account_id()));
+
+		assert!(matches!(apply(sign(Bob, 3, issue(BlakeTwo256::hash(b"new")))), Ok(Err(_))));
+		// Credentials issued before stay revocable by their issuer.
+		let revoke = Call::Credentials(pallet_credentials::Call::revoke_credential { credential });
+		assert_eq!(apply(sign(Bob, 4, revoke)), Ok(Ok(())));
+		assert_eq!(Credentials::is_revoked(&credential), Some(true));
+	});
+}
+
+#[test]
+fn governance_force_revokes_credentials() {
+	new_test_ext().execute_with(|| {
+		let schema = BlakeTwo256::hash(b"schema");
+		let credential = BlakeTwo256::hash(b"credential");
+		let (add, setup) = issuer_setup(Bob.to_account_id(), schema);
+		assert_eq!(apply(sign(Alice, 0, add)), Ok(Ok(())));
+		assert_eq!(apply(sign(Bob, 0, setup)), Ok(Ok(())));
+		assert_eq!(apply(sign(Bob, 1, issue(credential))), Ok(Ok(())));
+
+		let revoke = Call::Credentials(pallet_credentials::Call::force_revoke { credential });
+		// Only the registrar origin, here sudo, can force a revocation, not even the issuer.
+		assert_eq!(apply(sign(Bob, 2, revoke.clone())), Ok(Err(DispatchError::BadOrigin)));
+		assert_eq!(Credentials::is_revoked(&credential), Some(false));
+
+		assert_eq!(apply(sign(Alice, 1, sudo(revoke))), Ok(Ok(())));
+		assert_eq!(Credentials::is_revoked(&credential), Some(true));
+		// The issuer keeps its issuing rights.
+		assert!(pallet_credentials::Issuers::<Runtime>::contains_key(Bob.to_account_id()));
+	});
+}
+
+#[test]
+fn authored_blocks_are_imported() {
+	let now = Call::Timestamp(pallet_timestamp::Call::set { now: 1_000 });
+	let extrinsics = vec![
+		UncheckedExtrinsic::new_unsigned(now),
+		sign(Alice, 0, transfer(Charlie.to_account_id(), 1_000)),
+		sign(Bob, 0, transfer(Charlie.to_account_id(), 2_000)),
+	];
+
+	let header = new_test_ext().execute_with(|| {
+		for xt in extrinsics.clone() {
+			assert_eq!(apply(xt), Ok(Ok(())));
+		}
+		Executive::finalize_block()
+	});
+
+	// Importing the block checks the state root, the extrinsics root and the digest.
+	genesis().execute_with(|| {
+		Executive::execute_block(Block::new(header, extrinsics));
+		assert_eq!(frame_system::Pallet::<Runtime>::block_number(), 1);
+		assert_eq!(Balances::free_balance(Charlie.to_account_id()), 3_000);
+	});
+}
@@ -0,0 +1,18 @@
+#!/usr/bin/env bash
+# This script is meant to be run on Unix/Linux based systems
+set -e
+
+echo "*** Benchmarking all runtime pallets ***"
+
+cd $(dirname ${BASH_SOURCE[0]})/..
+
+OUTPUT=${1:-weights}
+STEPS=${STEPS:-50}
+REPEAT=${REPEAT:-20}
+
+cargo build --release --features runtime-benchmarks
+
+./target/release/node-template benchmark-all \
+	--steps $STEPS \
+	--repeat $REPEAT \
+	--output $OUTPUT
@@ -0,0 +1,11 @@
+// Return the number of Aura authorities reported by `HealthApi_health` on the node.
+async function run(nodeName, networkInfo) {
+	const { wsUri, userDefinedTypes } = networkInfo.nodesByName[nodeName];
+	const api = await zombie.connect(wsUri, userDefinedTypes);
+
+	// `Health` starts with the block number and the number of authorities, both `u32`.
+	const health = (await api.rpc.state.call("HealthApi_health", "0x")).toU8a(true);
+	return new DataView(health.buffer, health.byteOffset).getUint32(4, true);
+}
+
+module.exports = { run };